@@ -1,4 +1,14 @@
-import {init, Vector3, World} from "../pkg3d";
+import {
+    ActiveHooks,
+    ColliderDesc,
+    EventQueue,
+    init,
+    JointData,
+    PhysicsHooks,
+    RigidBodyDesc,
+    Vector3,
+    World,
+} from "../pkg3d";
 
 describe("3d/World", () => {
     let world: World;
@@ -20,4 +30,260 @@ describe("3d/World", () => {
     test("constructor", () => {
         expect(world.colliders.len()).toBe(0);
     });
+
+    test("invalid handles", () => {
+        let body = world.createRigidBody(RigidBodyDesc.dynamic());
+        let collider = world.createCollider(ColliderDesc.ball(0.5), body);
+        world.removeRigidBody(body);
+
+        expect(() => body.translation()).toThrow("Invalid RigidBody reference");
+        expect(() => collider.translation()).toThrow(
+            "Invalid Collider reference",
+        );
+
+        // The world must still be usable after catching these errors.
+        let body2 = world.createRigidBody(
+            RigidBodyDesc.dynamic().setLinvel(1.0, 0.0, 0.0),
+        );
+        world.createCollider(ColliderDesc.ball(0.5), body2);
+        expect(() => world.step()).not.toThrow();
+        expect(body2.translation().x).toBeGreaterThan(0.0);
+    });
+
+    test("substeps", () => {
+        let world2 = new World(new Vector3(0, 9.8, 0));
+        world2.timestep = world.timestep / 4;
+        let body = world.createRigidBody(RigidBodyDesc.dynamic());
+        let body2 = world2.createRigidBody(RigidBodyDesc.dynamic());
+        world.createCollider(ColliderDesc.ball(0.5), body);
+        world2.createCollider(ColliderDesc.ball(0.5), body2);
+
+        world.step(undefined, undefined, 4);
+        for (let i = 0; i < 4; ++i) world2.step();
+
+        expect(body.translation().y).toBeCloseTo(body2.translation().y, 5);
+        expect(body.linvel().y).toBeCloseTo(body2.linvel().y, 5);
+        world2.free();
+
+        // The motion of kinematic bodies is spread over all the substeps.
+        let platform = world.createRigidBody(
+            RigidBodyDesc.kinematicPositionBased(),
+        );
+        platform.setNextKinematicTranslation(new Vector3(1.0, 0.0, 0.0));
+        world.step(undefined, undefined, 4);
+        expect(platform.translation().x).toBeCloseTo(1.0, 5);
+        expect(platform.linvel().x).toBeCloseTo(1.0 / world.timestep, 3);
+
+        expect(() => world.step(undefined, undefined, 0)).toThrow(RangeError);
+        expect(() => world.step(undefined, undefined, 1.5)).toThrow(RangeError);
+    });
+
+    test("velocity caps", () => {
+        let body = world.createRigidBody(RigidBodyDesc.dynamic());
+        world.createCollider(ColliderDesc.ball(0.5), body);
+        body.setMaxLinvel(2.0);
+        body.applyImpulse(new Vector3(1000.0, 0.0, 0.0), true);
+        world.step();
+
+        let linvel = body.linvel();
+        let speed = Math.hypot(linvel.x, linvel.y, linvel.z);
+        expect(speed).toBeLessThanOrEqual(2.0 + 1.0e-5);
+        expect(() => body.setMaxLinvel(-1.0)).toThrow(RangeError);
+        expect(() => body.setMaxAngvel(NaN)).toThrow(RangeError);
+
+        // The caps are part of snapshots.
+        let restored = World.restoreSnapshot(world.takeSnapshot());
+        expect(restored.getRigidBody(body.handle).maxLinvel()).toBe(2.0);
+        restored.free();
+    });
+
+    test("clear", () => {
+        let ground = world.createRigidBody(RigidBodyDesc.fixed());
+        world.createCollider(ColliderDesc.cuboid(10.0, 0.1, 10.0), ground);
+        let ball = world.createRigidBody(
+            RigidBodyDesc.dynamic().setTranslation(0.0, -0.55, 0.0),
+        );
+        world.createCollider(ColliderDesc.ball(0.5), ball);
+        world.step();
+        expect(world.narrowPhase.numContactPairs()).toBeGreaterThan(0);
+
+        world.clear();
+        expect(world.bodies.len()).toBe(0);
+        expect(world.colliders.len()).toBe(0);
+        expect(world.narrowPhase.numContactPairs()).toBe(0);
+
+        // A ball added where the ground was must fall freely.
+        let ball2 = world.createRigidBody(RigidBodyDesc.dynamic());
+        world.createCollider(ColliderDesc.ball(0.5), ball2);
+        world.step();
+        expect(world.narrowPhase.numContactPairs()).toBe(0);
+        expect(ball2.linvel().y).toBeCloseTo(9.8 * world.timestep, 5);
+    });
+
+    test("collider world position", () => {
+        let body = world.createRigidBody(RigidBodyDesc.fixed());
+        let collider = world.createCollider(
+            ColliderDesc.ball(0.5).setTranslation(1.0, 0.0, 0.0),
+            body,
+        );
+        world.step();
+
+        // Move the parent, then the collider, without stepping in-between.
+        body.setTranslation(new Vector3(5.0, 0.0, 0.0), true);
+        collider.setTranslation(new Vector3(5.0, 2.0, 0.0));
+        world.step();
+
+        let tra = collider.translation();
+        expect(tra.x).toBeCloseTo(5.0, 5);
+        expect(tra.y).toBeCloseTo(2.0, 5);
+        expect(tra.z).toBeCloseTo(0.0, 5);
+        expect(collider.translationWrtParent().x).toBeCloseTo(0.0, 5);
+        expect(collider.translationWrtParent().y).toBeCloseTo(2.0, 5);
+    });
+
+    test("scale shape", () => {
+        let collider = world.createCollider(ColliderDesc.ball(0.5));
+        expect(collider.scaleShape(new Vector3(0.0, 0.0, 0.0))).toBe(false);
+        expect(collider.scaleShape(new Vector3(-1.0, -1.0, -1.0))).toBe(false);
+        expect(collider.scaleShape(new Vector3(NaN, NaN, NaN))).toBe(false);
+        expect(collider.radius()).toBe(0.5);
+        expect(collider.scaleShape(new Vector3(2.0, 2.0, 2.0))).toBe(true);
+        expect(collider.radius()).toBe(1.0);
+    });
+
+    test("world anchor after restore", () => {
+        let params = JointData.spherical(
+            new Vector3(0.0, 1.0, 0.0),
+            new Vector3(0.0, 0.0, 0.0),
+        );
+        let body = world.createRigidBody(RigidBodyDesc.dynamic());
+        world.createImpulseJoint(params, body, null, true);
+        let anchor = world.worldAnchorBody();
+
+        let restored = World.restoreSnapshot(world.takeSnapshot());
+        let numBodies = restored.bodies.len();
+        expect(restored.worldAnchorBody().handle).toBe(anchor.handle);
+
+        let body2 = restored.createRigidBody(RigidBodyDesc.dynamic());
+        let joint = restored.createImpulseJoint(params, body2, null, true);
+        expect(restored.bodies.len()).toBe(numBodies + 1);
+        expect(joint.body2().handle).toBe(anchor.handle);
+        restored.free();
+    });
+
+    test("merge worlds", () => {
+        // Occupy the first handles of `world` so the merged handles differ.
+        world.createCollider(
+            ColliderDesc.ball(0.5),
+            world.createRigidBody(RigidBodyDesc.fixed()),
+        );
+
+        let other = new World(new Vector3(0.0, 9.8, 0.0));
+        let params = JointData.spherical(
+            new Vector3(0.0, 1.0, 0.0),
+            new Vector3(0.0, 0.0, 0.0),
+        );
+        let a = other.createRigidBody(
+            RigidBodyDesc.dynamic().setTranslation(1.0, 2.0, 3.0),
+        );
+        let b = other.createRigidBody(RigidBodyDesc.dynamic());
+        let c = other.createRigidBody(RigidBodyDesc.dynamic());
+        let collider = other.createCollider(ColliderDesc.ball(0.5), a);
+        let impulseJoint = other.createImpulseJoint(params, a, b, true);
+        let multibodyJoint = other.createMultibodyJoint(params, b, c, true);
+
+        let remap = world.mergeFrom(other);
+        expect(remap.bodies.size).toBe(3);
+        expect(remap.colliders.size).toBe(1);
+        expect(remap.impulseJoints.size).toBe(1);
+        expect(remap.multibodyJoints.size).toBe(1);
+        expect(world.bodies.len()).toBe(4);
+
+        let a2 = world.getRigidBody(remap.bodies.get(a.handle));
+        let b2 = world.getRigidBody(remap.bodies.get(b.handle));
+        let c2 = world.getRigidBody(remap.bodies.get(c.handle));
+        expect(a2.translation().z).toBe(3.0);
+
+        let collider2 = world.getCollider(remap.colliders.get(collider.handle));
+        expect(collider2.parent().handle).toBe(a2.handle);
+
+        let impulseJoint2 = world.getImpulseJoint(
+            remap.impulseJoints.get(impulseJoint.handle),
+        );
+        expect(impulseJoint2.body1().handle).toBe(a2.handle);
+        expect(impulseJoint2.body2().handle).toBe(b2.handle);
+
+        let multibodyJoint2 = world.getMultibodyJoint(
+            remap.multibodyJoints.get(multibodyJoint.handle),
+        );
+        expect(multibodyJoint2.bodyHandle1()).toBe(b2.handle);
+        expect(multibodyJoint2.bodyHandle2()).toBe(c2.handle);
+
+        // The other world is left unchanged.
+        expect(other.bodies.len()).toBe(3);
+        other.free();
+        world.step();
+    });
+
+    test("modify solver contacts", () => {
+        world.gravity = new Vector3(0.0, -9.81, 0.0);
+        let eventQueue = new EventQueue(true);
+        world.createCollider(
+            ColliderDesc.cuboid(10.0, 0.1, 10.0).setActiveHooks(
+                ActiveHooks.MODIFY_SOLVER_CONTACTS,
+            ),
+        );
+        let box = world.createRigidBody(
+            RigidBodyDesc.dynamic().setTranslation(0.0, 0.6, 0.0),
+        );
+        world.createCollider(ColliderDesc.cuboid(0.5, 0.5, 0.5), box);
+
+        let numCalls = 0;
+        let hooks: PhysicsHooks = {
+            filterContactPair: () => null,
+            filterIntersectionPair: () => true,
+            modifySolverContacts: (c1, c2, b1, b2, contacts) => {
+                numCalls += 1;
+                // Friction, restitution, then tangent velocity of each contact.
+                for (let i = 0; i < contacts.length; i += 5) {
+                    contacts[i + 2] = 1.0;
+                }
+            },
+        };
+
+        for (let i = 0; i < 60; ++i) world.step(eventQueue, hooks);
+        expect(numCalls).toBeGreaterThan(0);
+        expect(Math.abs(box.linvel().x)).toBeGreaterThan(0.5);
+        expect(Math.abs(box.linvel().z)).toBeLessThan(1.0e-3);
+
+        // Exceptions thrown by the hook are ignored.
+        hooks.modifySolverContacts = () => {
+            throw Error("hook failure");
+        };
+        expect(() => world.step(eventQueue, hooks)).not.toThrow();
+
+        eventQueue.free();
+    });
+
+    test("heightfield heights", () => {
+        // 3x3 cells, i.e., a 4x4 height matrix.
+        let heights = new Float32Array(16);
+        let collider = world.createCollider(
+            ColliderDesc.heightfield(3, 3, heights, new Vector3(1, 1, 1)),
+        );
+
+        expect(collider.setHeightfieldHeight(3, 3, 2.0)).toBe(true);
+        expect(collider.heightfieldHeight(3, 3)).toBe(2.0);
+        expect(collider.setHeightfieldHeight(4, 0, 1.0)).toBe(false);
+
+        // A 2x2 patch covering the last two rows and columns.
+        let patch = new Float32Array([1.0, 2.0, 3.0, 4.0]);
+        expect(collider.setHeightfieldHeights(2, 2, 2, patch)).toBe(true);
+        expect(collider.heightfieldHeight(2, 2)).toBe(1.0);
+        expect(collider.heightfieldHeight(3, 2)).toBe(2.0);
+        expect(collider.heightfieldHeight(2, 3)).toBe(3.0);
+        expect(collider.heightfieldHeight(3, 3)).toBe(4.0);
+        expect(collider.setHeightfieldHeights(3, 3, 2, patch)).toBe(false);
+        expect(collider.heightfieldHeight(3, 3)).toBe(4.0);
+    });
 });
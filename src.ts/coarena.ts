@@ -35,6 +35,7 @@ export class Coarena<T> {
 
     public clear() {
         this.data = new Array<T>();
+        this.size = 0;
     }
 
     public get(handle: number): T | null {
@@ -68,3 +69,17 @@ export class Coarena<T> {
         return this.uconv[0];
     }
 }
+
+/**
+ * Flattens a handle remap table into the `[oldHandle, newHandle]` pairs expected by the
+ * `mergeFrom` methods of the raw sets.
+ */
+export function flattenRemap(remap: Map<number, number>): Float64Array {
+    let result = new Float64Array(remap.size * 2);
+    let i = 0;
+    remap.forEach((newHandle, oldHandle) => {
+        result[i++] = oldHandle;
+        result[i++] = newHandle;
+    });
+    return result;
+}
@@ -81,14 +81,24 @@ export class KinematicCharacterController {
      */
     public setUp(vector: Vector) {
         let rawVect = VectorOps.intoRaw(vector);
-        return this.raw.setUp(rawVect);
+        this.raw.setUp(rawVect);
         rawVect.free();
     }
 
+    /**
+     * Indicates if this character controller applies impulses to the dynamic rigid-bodies it
+     * collides with while moving.
+     */
     public applyImpulsesToDynamicBodies(): boolean {
         return this._applyImpulsesToDynamicBodies;
     }
 
+    /**
+     * Sets whether this character controller applies impulses to the dynamic rigid-bodies it
+     * collides with while moving.
+     *
+     * @param enabled - If `true`, dynamic rigid-bodies hit by the character are pushed away.
+     */
     public setApplyImpulsesToDynamicBodies(enabled: boolean) {
         this._applyImpulsesToDynamicBodies = enabled;
     }
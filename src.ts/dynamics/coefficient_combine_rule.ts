@@ -4,7 +4,12 @@
  * Use this when configuring the `ColliderDesc` to specify
  * how friction and restitution coefficient should be combined
  * in a contact.
+ *
+ * When the two colliders involved in a contact use different rules, the rule with the
+ * highest value is applied, i.e., `Max` takes precedence over `Multiply`, which takes
+ * precedence over `Min`, which takes precedence over `Average`.
  */
+// NOTE: must match the variants of the CoefficientCombineRule on the Rust side.
 export enum CoefficientCombineRule {
     Average = 0,
     Min = 1,
@@ -41,7 +41,7 @@ export enum MotorModel {
 }
 
 export class ImpulseJoint {
-    protected rawSet: RawImpulseJointSet; // The ImpulseJoint won't need to free this.
+    private _rawSet: RawImpulseJointSet; // The ImpulseJoint won't need to free this.
     protected bodySet: RigidBodySet; // The ImpulseJoint won’t need to free this.
     handle: ImpulseJointHandle;
 
@@ -50,7 +50,7 @@ export class ImpulseJoint {
         bodySet: RigidBodySet,
         handle: ImpulseJointHandle,
     ) {
-        this.rawSet = rawSet;
+        this._rawSet = rawSet;
         this.bodySet = bodySet;
         this.handle = handle;
     }
@@ -86,7 +86,18 @@ export class ImpulseJoint {
      * not been deleted from the joint set yet).
      */
     public isValid(): boolean {
-        return this.rawSet.contains(this.handle);
+        return this._rawSet.contains(this.handle);
+    }
+
+    // The handle is checked before calling into the raw set: an invalid handle
+    // throwing from within WASM would leave the whole set borrowed, and unusable
+    // by any later call (including the simulation step).
+    protected get rawSet(): RawImpulseJointSet {
+        if (!this._rawSet.contains(this.handle))
+            throw Error(
+                "Invalid ImpulseJoint reference. It may have been removed from the physics World.",
+            );
+        return this._rawSet;
     }
 
     /**
@@ -189,6 +200,19 @@ export class ImpulseJoint {
     public contactsEnabled(): boolean {
         return this.rawSet.jointContactsEnabled(this.handle);
     }
+
+    /**
+     * The impulses applied by this joint during the last step.
+     *
+     * These are the impulses along each of the joint's axes, expressed in the local frame of
+     * the joint attached to the first rigid-body: first the linear impulses (in N·s, one per
+     * linear axis), then the angular impulses (in N·m·s, one per angular axis). Dividing them by
+     * the timestep length gives the corresponding forces and torques, e.g., to break the joint
+     * once the stress exceeds a threshold.
+     */
+    public impulses(): Float32Array {
+        return this.rawSet.jointImpulses(this.handle);
+    }
 }
 
 export class UnitImpulseJoint extends ImpulseJoint {
@@ -292,6 +316,38 @@ export class RevoluteImpulseJoint extends UnitImpulseJoint {
 
 // #if DIM3
 export class SphericalImpulseJoint extends ImpulseJoint {
+    /**
+     * Limits the rotation of this joint, e.g., to keep the shoulders and hips of a ragdoll
+     * within anatomical ranges.
+     *
+     * The twist is the rotation around the local `x` axis of the joint frames, and the swing is
+     * the rotation around their local `y` and `z` axes. Each swing axis is limited independently,
+     * so the allowed swing region is a pyramid rather than a perfect cone.
+     *
+     * @param maxSwingAngle - The maximum swing angle, in radians, on each side.
+     * @param maxTwistAngle - The maximum twist angle, in radians, on each side.
+     */
+    public setSwingTwistLimits(maxSwingAngle: number, maxTwistAngle: number) {
+        this.rawSet.jointSetLimits(
+            this.handle,
+            RawJointAxis.AngX,
+            -maxTwistAngle,
+            maxTwistAngle,
+        );
+        this.rawSet.jointSetLimits(
+            this.handle,
+            RawJointAxis.AngY,
+            -maxSwingAngle,
+            maxSwingAngle,
+        );
+        this.rawSet.jointSetLimits(
+            this.handle,
+            RawJointAxis.AngZ,
+            -maxSwingAngle,
+            maxSwingAngle,
+        );
+    }
+
     /* Unsupported by this alpha release.
     public configureMotorModel(model: MotorModel) {
         this.rawSet.jointConfigureMotorModel(this.handle, model);
@@ -1,5 +1,5 @@
 import {RawImpulseJointSet} from "../raw";
-import {Coarena} from "../coarena";
+import {Coarena, flattenRemap} from "../coarena";
 import {RigidBodySet} from "./rigid_body_set";
 import {
     RevoluteImpulseJoint,
@@ -118,6 +118,17 @@ export class ImpulseJointSet {
         this.map.delete(handle);
     }
 
+    /**
+     * Removes all the joints from this set.
+     *
+     * All the joint handles and objects obtained from this set become invalid. Prefer
+     * `World.clear` which also clears the other structures referencing them.
+     */
+    public clear() {
+        this.raw.clear();
+        this.map.clear();
+    }
+
     /**
      * The number of joints on this set.
      */
@@ -134,6 +145,37 @@ export class ImpulseJointSet {
         return this.get(handle) != null;
     }
 
+    /**
+     * Inserts a copy of every joint of another set into this set.
+     *
+     * The other set is left unchanged.
+     *
+     * @param bodies - The set containing the copies of the rigid-bodies attached to the joints.
+     * @param other - The set of joints to copy.
+     * @param bodyRemap - The remap table returned by `RigidBodySet.mergeFrom`. Joints attached
+     *   to a rigid-body missing from this table are skipped.
+     * @returns A map from the handles of the joints of `other` to the handles of their copies.
+     */
+    public mergeFrom(
+        bodies: RigidBodySet,
+        other: ImpulseJointSet,
+        bodyRemap: Map<RigidBodyHandle, RigidBodyHandle>,
+    ): Map<ImpulseJointHandle, ImpulseJointHandle> {
+        let rawRemap = this.raw.mergeFrom(other.raw, flattenRemap(bodyRemap));
+        let remap = new Map<ImpulseJointHandle, ImpulseJointHandle>();
+
+        for (let i = 0; i < rawRemap.length; i += 2) {
+            let handle = rawRemap[i + 1];
+            this.map.set(
+                handle,
+                ImpulseJoint.newTyped(this.raw, bodies, handle),
+            );
+            remap.set(rawRemap[i], handle);
+        }
+
+        return remap;
+    }
+
     /**
      * Gets the joint with the given handle.
      *
@@ -32,6 +32,35 @@ export class IntegrationParameters {
         return this.raw.erp;
     }
 
+    /**
+     * The damping ratio used by the springs for Baumgarte constraints stabilization (default: `0.25`).
+     */
+    get dampingRatio(): number {
+        return this.raw.dampingRatio;
+    }
+
+    /**
+     * The Error Reduction Parameter for joints in `[0, 1]` is the proportion of
+     * the positional error to be corrected at each time step (default: `1.0`).
+     */
+    get jointErp(): number {
+        return this.raw.jointErp;
+    }
+
+    /**
+     * The damping ratio used by the springs for joint constraints stabilization (default: `1.0`).
+     */
+    get jointDampingRatio(): number {
+        return this.raw.jointDampingRatio;
+    }
+
+    /**
+     * Maximum amount of penetration the solver will attempt to resolve in one timestep (default: `10.0`).
+     */
+    get maxPenetrationCorrection(): number {
+        return this.raw.maxPenetrationCorrection;
+    }
+
     /**
      * Amount of penetration the engine wont attempt to correct (default: `0.001m`).
      */
@@ -41,6 +70,10 @@ export class IntegrationParameters {
 
     /**
      * The maximal distance separating two objects that will generate predictive contacts (default: `0.002`).
+     *
+     * This margin applies to every collider of the world: the version of Rapier used by these
+     * bindings doesn't support setting a contact skin on individual colliders. Increasing it
+     * reduces tunneling of thin or fast objects, at the cost of generating more contacts.
      */
     get predictionDistance(): number {
         return this.raw.predictionDistance;
@@ -48,13 +81,19 @@ export class IntegrationParameters {
 
     /**
      * Maximum number of iterations performed by the velocity constraints solver (default: `4`).
+     *
+     * Increasing it makes stacks of rigid-bodies and chains of joints stiffer, e.g., to keep tall
+     * stacks of boxes from sinking into each other, at the cost of a slower simulation step. It
+     * can be changed freely between two steps and must be at least `1`.
      */
     get maxVelocityIterations(): number {
         return this.raw.maxVelocityIterations;
     }
 
     /**
-     * Maximum number of friction iterations performed by the position-based constraints solver (default: `1`).
+     * Maximum number of friction iterations performed by the velocity constraints solver (default: `1`).
+     *
+     * These are performed in addition to `maxVelocityIterations`, for the friction constraints only.
      */
     get maxVelocityFrictionIterations(): number {
         return this.raw.maxVelocityFrictionIterations;
@@ -82,38 +121,86 @@ export class IntegrationParameters {
     }
 
     set dt(value: number) {
+        if (!(value >= 0.0))
+            throw RangeError("The timestep length must be non-negative.");
         this.raw.dt = value;
     }
 
     set erp(value: number) {
+        if (!(value >= 0.0 && value <= 1.0))
+            throw RangeError("The erp must be in [0, 1].");
         this.raw.erp = value;
     }
 
+    set dampingRatio(value: number) {
+        if (!(value >= 0.0))
+            throw RangeError("The damping ratio must be non-negative.");
+        this.raw.dampingRatio = value;
+    }
+
+    set jointErp(value: number) {
+        if (!(value >= 0.0 && value <= 1.0))
+            throw RangeError("The joint erp must be in [0, 1].");
+        this.raw.jointErp = value;
+    }
+
+    set jointDampingRatio(value: number) {
+        if (!(value >= 0.0))
+            throw RangeError("The joint damping ratio must be non-negative.");
+        this.raw.jointDampingRatio = value;
+    }
+
+    set maxPenetrationCorrection(value: number) {
+        if (!(value >= 0.0))
+            throw RangeError(
+                "The max penetration correction must be non-negative.",
+            );
+        this.raw.maxPenetrationCorrection = value;
+    }
+
     set allowedLinearError(value: number) {
+        if (!(value >= 0.0))
+            throw RangeError("The allowed linear error must be non-negative.");
         this.raw.allowedLinearError = value;
     }
 
     set predictionDistance(value: number) {
+        if (!(value >= 0.0))
+            throw RangeError("The prediction distance must be non-negative.");
         this.raw.predictionDistance = value;
     }
 
     set maxVelocityIterations(value: number) {
+        if (!(value >= 1))
+            throw RangeError("The number of velocity iterations must be >= 1.");
         this.raw.maxVelocityIterations = value;
     }
 
     set maxVelocityFrictionIterations(value: number) {
+        if (!(value >= 0))
+            throw RangeError(
+                "The number of friction iterations must be non-negative.",
+            );
         this.raw.maxVelocityFrictionIterations = value;
     }
 
     set maxStabilizationIterations(value: number) {
+        if (!(value >= 0))
+            throw RangeError(
+                "The number of stabilization iterations must be non-negative.",
+            );
         this.raw.maxStabilizationIterations = value;
     }
 
     set minIslandSize(value: number) {
+        if (!(value >= 1))
+            throw RangeError("The minimum island size must be >= 1.");
         this.raw.minIslandSize = value;
     }
 
     set maxCcdSubsteps(value: number) {
+        if (!(value >= 1))
+            throw RangeError("The number of CCD substeps must be >= 1.");
         this.raw.maxCcdSubsteps = value;
     }
 }
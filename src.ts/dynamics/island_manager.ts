@@ -34,4 +34,12 @@ export class IslandManager {
     public forEachActiveRigidBodyHandle(f: (handle: RigidBodyHandle) => void) {
         this.raw.forEachActiveRigidBodyHandle(f);
     }
+
+    /**
+     * The number of active dynamic rigid-bodies, i.e., the number of handles visited by
+     * `this.forEachActiveRigidBodyHandle`.
+     */
+    public numActiveRigidBodies(): number {
+        return this.raw.numActiveRigidBodies();
+    }
 }
@@ -7,6 +7,7 @@ import {
     PrismaticImpulseJoint,
     RevoluteImpulseJoint,
 } from "./impulse_joint";
+import {RigidBodyHandle} from "./rigid_body";
 
 // #if DIM3
 import {Quaternion} from "../math";
@@ -19,11 +20,11 @@ import {SphericalImpulseJoint} from "./impulse_joint";
 export type MultibodyJointHandle = number;
 
 export class MultibodyJoint {
-    protected rawSet: RawMultibodyJointSet; // The MultibodyJoint won't need to free this.
+    private _rawSet: RawMultibodyJointSet; // The MultibodyJoint won't need to free this.
     handle: MultibodyJointHandle;
 
     constructor(rawSet: RawMultibodyJointSet, handle: MultibodyJointHandle) {
-        this.rawSet = rawSet;
+        this._rawSet = rawSet;
         this.handle = handle;
     }
 
@@ -52,30 +53,43 @@ export class MultibodyJoint {
      * not been deleted from the joint set yet).
      */
     public isValid(): boolean {
-        return this.rawSet.contains(this.handle);
+        return this._rawSet.contains(this.handle);
+    }
+
+    // The handle is checked before calling into the raw set: an invalid handle
+    // throwing from within WASM would leave the whole set borrowed, and unusable
+    // by any later call (including the simulation step).
+    protected get rawSet(): RawMultibodyJointSet {
+        if (!this._rawSet.contains(this.handle))
+            throw Error(
+                "Invalid MultibodyJoint reference. It may have been removed from the physics World.",
+            );
+        return this._rawSet;
+    }
+
+    /**
+     * The unique integer identifier of the first rigid-body this joint it attached to.
+     *
+     * This is the rigid-body of the parent link in the multibody.
+     */
+    public bodyHandle1(): RigidBodyHandle {
+        return this.rawSet.jointBodyHandle1(this.handle);
+    }
+
+    /**
+     * The unique integer identifier of the second rigid-body this joint is attached to.
+     */
+    public bodyHandle2(): RigidBodyHandle {
+        return this.rawSet.jointBodyHandle2(this.handle);
+    }
+
+    /**
+     * The type of this joint given as a string.
+     */
+    public type(): JointType {
+        return this.rawSet.jointType(this.handle);
     }
 
-    // /**
-    //  * The unique integer identifier of the first rigid-body this joint it attached to.
-    //  */
-    // public bodyHandle1(): RigidBodyHandle {
-    //     return this.rawSet.jointBodyHandle1(this.handle);
-    // }
-    //
-    // /**
-    //  * The unique integer identifier of the second rigid-body this joint is attached to.
-    //  */
-    // public bodyHandle2(): RigidBodyHandle {
-    //     return this.rawSet.jointBodyHandle2(this.handle);
-    // }
-    //
-    // /**
-    //  * The type of this joint given as a string.
-    //  */
-    // public type(): JointType {
-    //     return this.rawSet.jointType(this.handle);
-    // }
-    //
     // // #if DIM3
     // /**
     //  * The rotation quaternion that aligns this joint's first local axis to the `x` axis.
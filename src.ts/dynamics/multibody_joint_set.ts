@@ -1,5 +1,5 @@
 import {RawMultibodyJointSet} from "../raw";
-import {Coarena} from "../coarena";
+import {Coarena, flattenRemap} from "../coarena";
 import {RigidBodySet} from "./rigid_body_set";
 import {
     MultibodyJoint,
@@ -59,13 +59,15 @@ export class MultibodyJointSet {
      * @param parent1 - The handle of the first rigid-body this joint is attached to.
      * @param parent2 - The handle of the second rigid-body this joint is attached to.
      * @param wakeUp - Should the attached rigid-bodies be awakened?
+     * @returns `null` if the joint can't be added to a multibody, e.g., because it would create
+     *   a kinematic loop, or because the second rigid-body already has a parent link.
      */
     public createJoint(
         desc: JointData,
         parent1: RigidBodyHandle,
         parent2: RigidBodyHandle,
         wakeUp: boolean,
-    ): MultibodyJoint {
+    ): MultibodyJoint | null {
         const rawParams = desc.intoRaw();
         const handle = this.raw.createJoint(
             rawParams,
@@ -74,6 +76,8 @@ export class MultibodyJointSet {
             wakeUp,
         );
         rawParams.free();
+        if (handle === undefined) return null;
+
         let joint = MultibodyJoint.newTyped(this.raw, handle);
         this.map.set(handle, joint);
         return joint;
@@ -98,6 +102,17 @@ export class MultibodyJointSet {
         this.map.delete(handle);
     }
 
+    /**
+     * Removes all the joints from this set.
+     *
+     * All the joint handles and objects obtained from this set become invalid. Prefer
+     * `World.clear` which also clears the other structures referencing them.
+     */
+    public clear() {
+        this.raw.clear();
+        this.map.clear();
+    }
+
     /**
      * The number of joints on this set.
      */
@@ -114,6 +129,32 @@ export class MultibodyJointSet {
         return this.get(handle) != null;
     }
 
+    /**
+     * Inserts a copy of every joint of another set into this set.
+     *
+     * The other set is left unchanged.
+     *
+     * @param other - The set of joints to copy.
+     * @param bodyRemap - The remap table returned by `RigidBodySet.mergeFrom`. Joints attached
+     *   to a rigid-body missing from this table are skipped.
+     * @returns A map from the handles of the joints of `other` to the handles of their copies.
+     */
+    public mergeFrom(
+        other: MultibodyJointSet,
+        bodyRemap: Map<RigidBodyHandle, RigidBodyHandle>,
+    ): Map<MultibodyJointHandle, MultibodyJointHandle> {
+        let rawRemap = this.raw.mergeFrom(other.raw, flattenRemap(bodyRemap));
+        let remap = new Map<MultibodyJointHandle, MultibodyJointHandle>();
+
+        for (let i = 0; i < rawRemap.length; i += 2) {
+            let handle = rawRemap[i + 1];
+            this.map.set(handle, MultibodyJoint.newTyped(this.raw, handle));
+            remap.set(rawRemap[i], handle);
+        }
+
+        return remap;
+    }
+
     /**
      * Gets the joint with the given handle.
      *
@@ -3,7 +3,7 @@ import {Rotation, RotationOps, Vector, VectorOps} from "../math";
 // #if DIM3
 import {SdpMatrix3, SdpMatrix3Ops} from "../math";
 // #endif
-import {Collider, ColliderSet} from "../geometry";
+import {Collider, ColliderSet, InteractionGroups} from "../geometry";
 
 /**
  * The integer identifier of a collider added to a `ColliderSet`.
@@ -43,11 +43,18 @@ export enum RigidBodyType {
     KinematicVelocityBased,
 }
 
+function checkDominanceGroup(group: number) {
+    if (!Number.isInteger(group) || group < -127 || group > 127)
+        throw RangeError(
+            "The dominance group must be an integer in the range [-127, +127].",
+        );
+}
+
 /**
  * A rigid-body.
  */
 export class RigidBody {
-    private rawSet: RawRigidBodySet; // The RigidBody won't need to free this.
+    private _rawSet: RawRigidBodySet; // The RigidBody won't need to free this.
     private colliderSet: ColliderSet;
     readonly handle: RigidBodyHandle;
 
@@ -61,7 +68,7 @@ export class RigidBody {
         colliderSet: ColliderSet,
         handle: RigidBodyHandle,
     ) {
-        this.rawSet = rawSet;
+        this._rawSet = rawSet;
         this.colliderSet = colliderSet;
         this.handle = handle;
     }
@@ -76,7 +83,18 @@ export class RigidBody {
      * not been deleted from the rigid-body set yet.
      */
     public isValid(): boolean {
-        return this.rawSet.contains(this.handle);
+        return this._rawSet.contains(this.handle);
+    }
+
+    // The handle is checked before calling into the raw set: an invalid handle
+    // throwing from within WASM would leave the whole set borrowed, and unusable
+    // by any later call (including the simulation step).
+    private get rawSet(): RawRigidBodySet {
+        if (!this._rawSet.contains(this.handle))
+            throw Error(
+                "Invalid RigidBody reference. It may have been removed from the physics World.",
+            );
+        return this._rawSet;
     }
 
     /**
@@ -103,8 +121,8 @@ export class RigidBody {
     /**
      * Locks or unlocks the ability of this rigid-body to translation along individual coordinate axes.
      *
-     * @param enableX - If `false`, this rigid-body will no longer rotate due to torques and impulses, along the X coordinate axis.
-     * @param enableY - If `false`, this rigid-body will no longer rotate due to torques and impulses, along the Y coordinate axis.
+     * @param enableX - If `false`, this rigid-body will no longer translate due to forces and impulses, along the X coordinate axis.
+     * @param enableY - If `false`, this rigid-body will no longer translate due to forces and impulses, along the Y coordinate axis.
      * @param wakeUp - If `true`, this rigid-body will be automatically awaken if it is currently asleep.
      */
     public setEnabledTranslations(
@@ -123,8 +141,8 @@ export class RigidBody {
     /**
      * Locks or unlocks the ability of this rigid-body to translation along individual coordinate axes.
      *
-     * @param enableX - If `false`, this rigid-body will no longer rotate due to torques and impulses, along the X coordinate axis.
-     * @param enableY - If `false`, this rigid-body will no longer rotate due to torques and impulses, along the Y coordinate axis.
+     * @param enableX - If `false`, this rigid-body will no longer translate due to forces and impulses, along the X coordinate axis.
+     * @param enableY - If `false`, this rigid-body will no longer translate due to forces and impulses, along the Y coordinate axis.
      * @param wakeUp - If `true`, this rigid-body will be automatically awaken if it is currently asleep.
      * @deprecated use `this.setEnabledTranslations` with the same arguments instead.
      */
@@ -133,16 +151,19 @@ export class RigidBody {
         enableY: boolean,
         wakeUp: boolean,
     ) {
-        this.setEnabledTranslations(enableX, enableX, wakeUp);
+        this.setEnabledTranslations(enableX, enableY, wakeUp);
     }
     // #endif
     // #if DIM3
     /**
      * Locks or unlocks the ability of this rigid-body to translate along individual coordinate axes.
      *
-     * @param enableX - If `false`, this rigid-body will no longer translate due to torques and impulses, along the X coordinate axis.
-     * @param enableY - If `false`, this rigid-body will no longer translate due to torques and impulses, along the Y coordinate axis.
-     * @param enableZ - If `false`, this rigid-body will no longer translate due to torques and impulses, along the Z coordinate axis.
+     * This can confine a rigid-body to a plane (e.g. enabling only X and Z for a hovering drone)
+     * or to a rail (enabling a single axis). The axes are expressed in world-space.
+     *
+     * @param enableX - If `false`, this rigid-body will no longer translate due to forces and impulses, along the X coordinate axis.
+     * @param enableY - If `false`, this rigid-body will no longer translate due to forces and impulses, along the Y coordinate axis.
+     * @param enableZ - If `false`, this rigid-body will no longer translate due to forces and impulses, along the Z coordinate axis.
      * @param wakeUp - If `true`, this rigid-body will be automatically awaken if it is currently asleep.
      */
     public setEnabledTranslations(
@@ -163,9 +184,9 @@ export class RigidBody {
     /**
      * Locks or unlocks the ability of this rigid-body to translate along individual coordinate axes.
      *
-     * @param enableX - If `false`, this rigid-body will no longer translate due to torques and impulses, along the X coordinate axis.
-     * @param enableY - If `false`, this rigid-body will no longer translate due to torques and impulses, along the Y coordinate axis.
-     * @param enableZ - If `false`, this rigid-body will no longer translate due to torques and impulses, along the Z coordinate axis.
+     * @param enableX - If `false`, this rigid-body will no longer translate due to forces and impulses, along the X coordinate axis.
+     * @param enableY - If `false`, this rigid-body will no longer translate due to forces and impulses, along the Y coordinate axis.
+     * @param enableZ - If `false`, this rigid-body will no longer translate due to forces and impulses, along the Z coordinate axis.
      * @param wakeUp - If `true`, this rigid-body will be automatically awaken if it is currently asleep.
      * @deprecated use `this.setEnabledTranslations` with the same arguments instead.
      */
@@ -230,15 +251,30 @@ export class RigidBody {
     /**
      * Sets the dominance group of this rigid-body.
      *
+     * A dynamic rigid-body with a higher dominance group behaves as if it had an infinite mass
+     * when it interacts with a dynamic rigid-body with a lower dominance group: it pushes the
+     * other body but is never pushed back.
+     *
      * @param group - The dominance group of this rigid-body. Must be a signed integer in the range [-127, +127].
      */
     public setDominanceGroup(group: number) {
+        checkDominanceGroup(group);
         this.rawSet.rbSetDominanceGroup(this.handle, group);
     }
 
     /**
      * Enable or disable CCD (Continuous Collision Detection) for this rigid-body.
      *
+     * CCD only runs on the steps where this rigid-body moves fast enough, relative to the
+     * thickness of its colliders, to risk tunneling. Use `this.isCcdActive` to check whether
+     * it was triggered. There is no per-body soft-CCD prediction distance, so CCD sensitivity
+     * can't be tuned beyond enabling it.
+     *
+     * CCD only considers the pairs of colliders whose collision groups allow them to collide;
+     * there is no CCD-specific group mask. To limit its cost, e.g., to only run it between fast
+     * projectiles and static walls, enable it only on the projectiles and give them collision
+     * groups that exclude the other projectiles when they don't need to collide with each other.
+     *
      * @param enabled - If `true`, CCD will be enabled for this rigid-body.
      */
     public enableCcd(enabled: boolean) {
@@ -342,9 +378,10 @@ export class RigidBody {
      * @param rotation - The rotation to set.
      * @param wakeUp - Forces the rigid-body to wake-up so it is properly affected by forces if it
      * wasn't moving before modifying its position.
+     * @returns `false` if the rotation was ignored because `rot` is a zero quaternion, `true` otherwise.
      */
-    public setRotation(rot: Rotation, wakeUp: boolean) {
-        this.rawSet.rbSetRotation(
+    public setRotation(rot: Rotation, wakeUp: boolean): boolean {
+        return this.rawSet.rbSetRotation(
             this.handle,
             rot.x,
             rot.y,
@@ -454,6 +491,58 @@ export class RigidBody {
 
     // #endif
 
+    /**
+     * Teleports this rigid-body to the given position.
+     *
+     * Both the current and next positions are set, so no artificial velocity is derived from the
+     * jump, whatever the rigid-body type. The linear and angular velocities are left unchanged:
+     * reset them with `setLinvel` and `setAngvel` if needed, e.g., when respawning.
+     *
+     * @param tra - The world-space translation of the rigid-body.
+     * @param rot - The world-space rotation of the rigid-body.
+     * @param wakeUp - Forces the rigid-body to wake-up so it is properly affected by forces if it
+     * wasn't moving before modifying its position.
+     */
+    public teleport(tra: Vector, rot: Rotation, wakeUp: boolean) {
+        let rawTra = VectorOps.intoRaw(tra);
+        let rawRot = RotationOps.intoRaw(rot);
+        this.rawSet.rbTeleport(this.handle, rawTra, rawRot, wakeUp);
+        rawTra.free();
+        rawRot.free();
+    }
+
+    /**
+     * Moves this rigid-body by integrating its current linear and angular velocities over `dt`.
+     *
+     * This is a purely positional update, e.g., to extrapolate a remote rigid-body between two
+     * authoritative updates: forces, gravity, contacts, and joints are ignored, and the
+     * velocities are left unchanged. The attached colliders are moved at the next step, or by
+     * `World.propagateModifiedBodyPositionsToColliders`.
+     *
+     * @param dt - The duration, in seconds, to extrapolate the position for.
+     */
+    public integrateVelocities(dt: number) {
+        this.rawSet.rbIntegrateVelocities(this.handle, dt);
+    }
+
+    /**
+     * If this rigid body is kinematic, sets both its future translation and rotation after the
+     * next timestep integration.
+     *
+     * This is equivalent to calling `setNextKinematicTranslation` and `setNextKinematicRotation`,
+     * but crosses the WASM boundary only once.
+     *
+     * @param tra - The kinematic translation to set.
+     * @param rot - The kinematic rotation to set.
+     */
+    public setNextKinematicPosition(tra: Vector, rot: Rotation) {
+        let rawTra = VectorOps.intoRaw(tra);
+        let rawRot = RotationOps.intoRaw(rot);
+        this.rawSet.rbSetNextKinematicPosition(this.handle, rawTra, rawRot);
+        rawTra.free();
+        rawRot.free();
+    }
+
     /**
      * The linear velocity of this rigid-body.
      */
@@ -481,6 +570,55 @@ export class RigidBody {
 
     // #endif
 
+    /**
+     * The linear velocity this rigid-body will have during the next timestep.
+     *
+     * For a position-based kinematic rigid-body, this is the velocity derived from its current
+     * translation and the one set by `setNextKinematicTranslation`. For other rigid-bodies, this
+     * is the same as `this.linvel()`.
+     *
+     * @param dt - The length of the next timestep, usually `world.timestep`.
+     */
+    public kinematicLinvel(dt: number): Vector {
+        return VectorOps.fromRaw(
+            this.rawSet.rbKinematicLinvel(this.handle, dt),
+        );
+    }
+
+    // #if DIM3
+    /**
+     * The angular velocity this rigid-body will have during the next timestep.
+     *
+     * For a position-based kinematic rigid-body, this is the velocity derived from its current
+     * rotation and the one set by `setNextKinematicRotation`. For other rigid-bodies, this is
+     * the same as `this.angvel()`.
+     *
+     * @param dt - The length of the next timestep, usually `world.timestep`.
+     */
+    public kinematicAngvel(dt: number): Vector {
+        return VectorOps.fromRaw(
+            this.rawSet.rbKinematicAngvel(this.handle, dt),
+        );
+    }
+
+    // #endif
+
+    // #if DIM2
+    /**
+     * The angular velocity this rigid-body will have during the next timestep.
+     *
+     * For a position-based kinematic rigid-body, this is the velocity derived from its current
+     * rotation and the one set by `setNextKinematicRotation`. For other rigid-bodies, this is
+     * the same as `this.angvel()`.
+     *
+     * @param dt - The length of the next timestep, usually `world.timestep`.
+     */
+    public kinematicAngvel(dt: number): number {
+        return this.rawSet.rbKinematicAngvel(this.handle, dt);
+    }
+
+    // #endif
+
     /**
      * The mass of this rigid-body.
      */
@@ -488,6 +626,30 @@ export class RigidBody {
         return this.rawSet.rbMass(this.handle);
     }
 
+    /**
+     * The kinetic energy of this rigid-body, including both its translational and rotational parts.
+     */
+    public kineticEnergy(): number {
+        return this.rawSet.rbKineticEnergy(this.handle);
+    }
+
+    /**
+     * The velocity of the given world-space point on this rigid-body.
+     *
+     * This accounts for both the linear velocity and the angular velocity of this
+     * rigid-body around its center of mass.
+     *
+     * @param point - The world-space point.
+     */
+    public velocityAtPoint(point: Vector): Vector {
+        const rawPoint = VectorOps.intoRaw(point);
+        const result = VectorOps.fromRaw(
+            this.rawSet.rbVelocityAtPoint(this.handle, rawPoint),
+        );
+        rawPoint.free();
+        return result;
+    }
+
     /**
      * The inverse mass taking into account translation locking.
      */
@@ -520,7 +682,7 @@ export class RigidBody {
 
     // #if DIM2
     /**
-     * The inverse of the principal angular inertia of the rigid-body.
+     * The square-root of the inverse of the principal angular inertia of the rigid-body.
      *
      * Components set to zero are assumed to be infinite along the corresponding principal axis.
      */
@@ -531,7 +693,7 @@ export class RigidBody {
 
     // #if DIM3
     /**
-     * The inverse of the principal angular inertia of the rigid-body.
+     * The square-root of the inverse of the principal angular inertia of the rigid-body.
      *
      * Components set to zero are assumed to be infinite along the corresponding principal axis.
      */
@@ -554,6 +716,8 @@ export class RigidBody {
     // #if DIM3
     /**
      * The angular inertia along the principal inertia axes of the rigid-body.
+     *
+     * These moments are expressed in the local frame given by `this.principalInertiaLocalFrame()`.
      */
     public principalInertia(): Vector {
         return VectorOps.fromRaw(this.rawSet.rbPrincipalInertia(this.handle));
@@ -621,6 +785,10 @@ export class RigidBody {
      * A sleeping body no longer moves and is no longer simulated by the physics engine unless
      * it is waken up. It can be woken manually with `this.wakeUp()` or automatically due to
      * external forces like contacts.
+     *
+     * This takes effect immediately: the velocities of the rigid-body are set to zero and it is
+     * excluded from the next simulation step, even if it has just been created. To create a
+     * rigid-body asleep in the first place, use `RigidBodyDesc.setSleeping`.
      */
     public sleep() {
         this.rawSet.rbSleep(this.handle);
@@ -646,6 +814,16 @@ export class RigidBody {
         return this.rawSet.rbIsCcdEnabled(this.handle);
     }
 
+    /**
+     * Is CCD active for this rigid-body during the current step?
+     *
+     * This is `true` only if CCD is enabled and this rigid-body moved fast enough during
+     * the last step to require it.
+     */
+    public isCcdActive(): boolean {
+        return this.rawSet.rbIsCcdActive(this.handle);
+    }
+
     /**
      * The number of colliders attached to this rigid-body.
      */
@@ -663,9 +841,117 @@ export class RigidBody {
         return this.colliderSet.get(this.rawSet.rbCollider(this.handle, i));
     }
 
+    /**
+     * The lower bound of the world-space axis-aligned bounding box enclosing all the colliders
+     * attached to this rigid-body, or `null` if it has no collider.
+     */
+    public aabbMin(): Vector | null {
+        return VectorOps.fromRaw(
+            this.rawSet.rbAabbMin(this.handle, this.colliderSet.raw),
+        );
+    }
+
+    /**
+     * The upper bound of the world-space axis-aligned bounding box enclosing all the colliders
+     * attached to this rigid-body, or `null` if it has no collider.
+     */
+    public aabbMax(): Vector | null {
+        return VectorOps.fromRaw(
+            this.rawSet.rbAabbMax(this.handle, this.colliderSet.raw),
+        );
+    }
+
+    /**
+     * The lower bound of the axis-aligned bounding box enclosing all the colliders attached to
+     * this rigid-body, swept over a timestep, or `null` if it has no collider.
+     *
+     * The swept box encloses the colliders at their current position and at the position
+     * predicted from the velocities and forces of this rigid-body after `dt`. This can serve as a
+     * coarse broad-phase for custom tunneling checks, e.g., to decide which rigid-bodies need CCD.
+     *
+     * @param dt - The length of the timestep, usually `world.timestep`.
+     */
+    public sweptAabbMin(dt: number): Vector | null {
+        return VectorOps.fromRaw(
+            this.rawSet.rbSweptAabbMin(this.handle, this.colliderSet.raw, dt),
+        );
+    }
+
+    /**
+     * The upper bound of the axis-aligned bounding box enclosing all the colliders attached to
+     * this rigid-body, swept over a timestep, or `null` if it has no collider.
+     *
+     * See `this.sweptAabbMin` for details.
+     *
+     * @param dt - The length of the timestep, usually `world.timestep`.
+     */
+    public sweptAabbMax(dt: number): Vector | null {
+        return VectorOps.fromRaw(
+            this.rawSet.rbSweptAabbMax(this.handle, this.colliderSet.raw, dt),
+        );
+    }
+
+    /**
+     * Sets the collision groups of all the colliders currently attached to this rigid-body.
+     *
+     * Colliders attached to this rigid-body afterwards keep their own collision groups.
+     * See the documentation of `InteractionGroups` for details on the used bit pattern.
+     *
+     * @param groups - The collision groups to set on each collider.
+     */
+    public setCollisionGroups(groups: InteractionGroups) {
+        this.rawSet.rbSetCollisionGroups(
+            this.handle,
+            groups,
+            this.colliderSet.raw,
+        );
+    }
+
+    /**
+     * Sets the solver groups of all the colliders currently attached to this rigid-body.
+     *
+     * Colliders attached to this rigid-body afterwards keep their own solver groups.
+     * See the documentation of `InteractionGroups` for details on the used bit pattern.
+     *
+     * @param groups - The solver groups to set on each collider.
+     */
+    public setSolverGroups(groups: InteractionGroups) {
+        this.rawSet.rbSetSolverGroups(
+            this.handle,
+            groups,
+            this.colliderSet.raw,
+        );
+    }
+
+    /**
+     * Sets the friction coefficient of all the colliders currently attached to this rigid-body.
+     *
+     * @param friction - The friction coefficient to set on each collider.
+     */
+    public setFriction(friction: number) {
+        this.rawSet.rbSetFriction(this.handle, friction, this.colliderSet.raw);
+    }
+
+    /**
+     * Sets the restitution coefficient of all the colliders currently attached to this rigid-body.
+     *
+     * @param restitution - The restitution coefficient to set on each collider.
+     */
+    public setRestitution(restitution: number) {
+        this.rawSet.rbSetRestitution(
+            this.handle,
+            restitution,
+            this.colliderSet.raw,
+        );
+    }
+
     /**
      * Sets whether this rigid-body is enabled or not.
      *
+     * A disabled rigid-body and its attached colliders are excluded from the simulation and from scene
+     * queries, but keep their handle, position, and velocities. This is cheaper than removing the
+     * rigid-body and inserting it again later.
+     *
      * @param enabled - Set to `false` to disable this rigid-body and all its attached colliders.
      */
     public setEnabled(enabled: boolean) {
@@ -700,6 +986,53 @@ export class RigidBody {
         return this.rawSet.rbIsSleeping(this.handle);
     }
 
+    /**
+     * Sets whether this rigid-body can be put to sleep automatically.
+     *
+     * A rigid-body that can't sleep keeps reacting to slow pushes, at the cost of always being
+     * simulated. Disabling sleeping wakes the rigid-body up. Enabling it restores the default
+     * sleep thresholds.
+     *
+     * @param canSleep - If `false`, this rigid-body will never fall asleep automatically.
+     */
+    public setCanSleep(canSleep: boolean) {
+        this.rawSet.rbSetCanSleep(this.handle, canSleep);
+    }
+
+    /**
+     * Can this rigid-body be put to sleep automatically?
+     */
+    public canSleep(): boolean {
+        return this.rawSet.rbCanSleep(this.handle);
+    }
+
+    /**
+     * Sets the velocity thresholds below which this rigid-body can be put to sleep.
+     *
+     * A rigid-body falls asleep once both its linear and angular velocities stayed below these
+     * thresholds for a short while. Lower thresholds make it less eager to sleep.
+     *
+     * @param linear - The linear velocity threshold. A negative value prevents sleeping.
+     * @param angular - The angular velocity threshold. A negative value prevents sleeping.
+     */
+    public setSleepThresholds(linear: number, angular: number) {
+        this.rawSet.rbSetSleepThresholds(this.handle, linear, angular);
+    }
+
+    /**
+     * The linear velocity threshold below which this rigid-body can be put to sleep.
+     */
+    public linearSleepThreshold(): number {
+        return this.rawSet.rbLinearSleepThreshold(this.handle);
+    }
+
+    /**
+     * The angular velocity threshold below which this rigid-body can be put to sleep.
+     */
+    public angularSleepThreshold(): number {
+        return this.rawSet.rbAngularSleepThreshold(this.handle);
+    }
+
     /**
      * Is the velocity of this rigid-body not zero?
      */
@@ -742,6 +1075,52 @@ export class RigidBody {
         return this.rawSet.rbAngularDamping(this.handle);
     }
 
+    /**
+     * Sets the maximum linear speed of this rigid-body.
+     *
+     * If its linear velocity exceeds this before or after a simulation step (or substep), it
+     * is scaled down to this magnitude. This only affects dynamic rigid-bodies. The cap is part of
+     * world snapshots.
+     *
+     * @param max - The maximum linear speed, or `Infinity` to remove the cap. Must be
+     *              non-negative.
+     */
+    public setMaxLinvel(max: number) {
+        if (!(max >= 0.0))
+            throw RangeError("The maximum linear speed must be non-negative.");
+        this.rawSet.rbSetMaxLinvel(this.handle, max);
+    }
+
+    /**
+     * The maximum linear speed of this rigid-body, or `Infinity` if it isn't capped.
+     */
+    public maxLinvel(): number {
+        return this.rawSet.rbMaxLinvel(this.handle);
+    }
+
+    /**
+     * Sets the maximum angular speed of this rigid-body.
+     *
+     * If its angular velocity exceeds this before or after a simulation step (or substep), it
+     * is scaled down to this magnitude. This only affects dynamic rigid-bodies. The cap is part of
+     * world snapshots.
+     *
+     * @param max - The maximum angular speed, in radians per second, or `Infinity` to
+     *              remove the cap. Must be non-negative.
+     */
+    public setMaxAngvel(max: number) {
+        if (!(max >= 0.0))
+            throw RangeError("The maximum angular speed must be non-negative.");
+        this.rawSet.rbSetMaxAngvel(this.handle, max);
+    }
+
+    /**
+     * The maximum angular speed of this rigid-body, or `Infinity` if it isn't capped.
+     */
+    public maxAngvel(): number {
+        return this.rawSet.rbMaxAngvel(this.handle);
+    }
+
     /**
      * Sets the linear damping factor applied to this rigid-body.
      *
@@ -752,7 +1131,10 @@ export class RigidBody {
     }
 
     /**
-     * Recompute the mass-properties of this rigid-bodies based on its currently attached colliders.
+     * Recompute the mass-properties of this rigid-body based on its currently attached colliders.
+     *
+     * This happens automatically at the next physics step, but can be called to read up-to-date mass
+     * properties right after changing the shape or density of an attached collider.
      */
     public recomputeMassPropertiesFromColliders() {
         this.rawSet.rbRecomputeMassPropertiesFromColliders(
@@ -770,7 +1152,7 @@ export class RigidBody {
      * This is only the "additional" mass because the total mass of the rigid-body is equal to the sum of this
      * additional mass and the mass computed from the colliders (with non-zero densities) attached to this rigid-body.
      *
-     * That total mass (which includes the attached colliders’ contributions) will be updated at the name physics step,
+     * That total mass (which includes the attached colliders’ contributions) will be updated at the next physics step,
      * or can be updated manually with `this.recomputeMassPropertiesFromColliders`.
      *
      * This will override any previous additional mass-properties set by `this.setAdditionalMass`,
@@ -792,13 +1174,18 @@ export class RigidBody {
      * sum of this additional mass-properties and the mass computed from the colliders (with non-zero densities) attached
      * to this rigid-body.
      *
-     * That total mass-properties (which include the attached colliders’ contributions) will be updated at the name
+     * That total mass-properties (which include the attached colliders’ contributions) will be updated at the next
      * physics step, or can be updated manually with `this.recomputeMassPropertiesFromColliders`.
      *
      * This will override any previous mass-properties set by `this.setAdditionalMass`,
      * `this.setAdditionalMassProperties`, `RigidBodyDesc.setAdditionalMass`, or `RigidBodyDesc.setAdditionalMassProperties`
      * for this rigid-body.
      *
+     * The `centerOfMass` is expressed in the local-space of this rigid-body, so this can be used to add a point mass
+     * (e.g. a heavy engine block) at a specific location, even on a rigid-body without any collider. The resulting center
+     * of mass is the mass-weighted average of this one and the colliders' one, and the angular inertia of both parts is
+     * combined relative to that resulting center of mass.
+     *
      * If `wake_up` is true then the rigid-body will be woken up if it was put to sleep because it did not move for a while.
      */
     public setAdditionalMassProperties(
@@ -835,13 +1222,18 @@ export class RigidBody {
      * sum of this additional mass-properties and the mass computed from the colliders (with non-zero densities) attached
      * to this rigid-body.
      *
-     * That total mass-properties (which include the attached colliders’ contributions) will be updated at the name
+     * That total mass-properties (which include the attached colliders’ contributions) will be updated at the next
      * physics step, or can be updated manually with `this.recomputeMassPropertiesFromColliders`.
      *
      * This will override any previous mass-properties set by `this.setAdditionalMass`,
      * `this.setAdditionalMassProperties`, `RigidBodyDesc.setAdditionalMass`, or `RigidBodyDesc.setAdditionalMassProperties`
      * for this rigid-body.
      *
+     * The `centerOfMass` is expressed in the local-space of this rigid-body, so this can be used to add a point mass
+     * (e.g. a heavy engine block) at a specific location, even on a rigid-body without any collider. The resulting center
+     * of mass is the mass-weighted average of this one and the colliders' one, and the angular inertia of both parts is
+     * combined relative to that resulting center of mass.
+     *
      * If `wake_up` is true then the rigid-body will be woken up if it was put to sleep because it did not move for a while.
      */
     public setAdditionalMassProperties(
@@ -871,9 +1263,46 @@ export class RigidBody {
         this.rawSet.rbSetAngularDamping(this.handle, factor);
     }
 
+    /**
+     * The sum of the user forces currently applied to this rigid-body.
+     *
+     * This is the force accumulated by `addForce` and `addForceAtPoint` that will be
+     * applied at the next simulation step.
+     */
+    public userForce(): Vector {
+        return VectorOps.fromRaw(this.rawSet.rbUserForce(this.handle));
+    }
+
+    // #if DIM2
+    /**
+     * The sum of the user torques currently applied to this rigid-body.
+     *
+     * This includes the torques induced by `addForceAtPoint`.
+     */
+    public userTorque(): number {
+        return this.rawSet.rbUserTorque(this.handle);
+    }
+
+    // #endif
+
+    // #if DIM3
+    /**
+     * The sum of the user torques currently applied to this rigid-body.
+     *
+     * This includes the torques induced by `addForceAtPoint`.
+     */
+    public userTorque(): Vector {
+        return VectorOps.fromRaw(this.rawSet.rbUserTorque(this.handle));
+    }
+
+    // #endif
+
     /**
      * Resets to zero the user forces (but not torques) applied to this rigid-body.
      *
+     * Forces added with `addForce` or `addForceAtPoint` are not cleared automatically
+     * after a simulation step: they keep being applied until this method is called.
+     *
      * @param wakeUp - should the rigid-body be automatically woken-up?
      */
     public resetForces(wakeUp: boolean) {
@@ -883,6 +1312,9 @@ export class RigidBody {
     /**
      * Resets to zero the user torques applied to this rigid-body.
      *
+     * Torques added with `addTorque` are not cleared automatically after a simulation
+     * step: they keep being applied until this method is called.
+     *
      * @param wakeUp - should the rigid-body be automatically woken-up?
      */
     public resetTorques(wakeUp: boolean) {
@@ -1007,6 +1439,54 @@ export class RigidBody {
         rawImpulse.free();
         rawPoint.free();
     }
+
+    /**
+     * Adds a force at the given local-space point of this rigid-body.
+     *
+     * The point follows the rigid-body as it moves, which is convenient for thrusters.
+     *
+     * @param force - the world-space force to add to the rigid-body.
+     * @param point - the point where the force is to be applied, expressed in the
+     *   local-space of the rigid-body.
+     * @param wakeUp - should the rigid-body be automatically woken-up?
+     */
+    public addForceAtLocalPoint(force: Vector, point: Vector, wakeUp: boolean) {
+        const rawForce = VectorOps.intoRaw(force);
+        const rawPoint = VectorOps.intoRaw(point);
+        this.rawSet.rbAddForceAtLocalPoint(
+            this.handle,
+            rawForce,
+            rawPoint,
+            wakeUp,
+        );
+        rawForce.free();
+        rawPoint.free();
+    }
+
+    /**
+     * Applies an impulse at the given local-space point of this rigid-body.
+     *
+     * @param impulse - the world-space impulse to apply on the rigid-body.
+     * @param point - the point where the impulse is to be applied, expressed in the
+     *   local-space of the rigid-body.
+     * @param wakeUp - should the rigid-body be automatically woken-up?
+     */
+    public applyImpulseAtLocalPoint(
+        impulse: Vector,
+        point: Vector,
+        wakeUp: boolean,
+    ) {
+        const rawImpulse = VectorOps.intoRaw(impulse);
+        const rawPoint = VectorOps.intoRaw(point);
+        this.rawSet.rbApplyImpulseAtLocalPoint(
+            this.handle,
+            rawImpulse,
+            rawPoint,
+            wakeUp,
+        );
+        rawImpulse.free();
+        rawPoint.free();
+    }
 }
 
 export class RigidBodyDesc {
@@ -1141,7 +1621,13 @@ export class RigidBodyDesc {
         return new RigidBodyDesc(RigidBodyType.Fixed);
     }
 
+    /**
+     * Sets the dominance group of the rigid-body to be created.
+     *
+     * @param group - The dominance group. Must be a signed integer in the range [-127, +127].
+     */
     public setDominanceGroup(group: number): RigidBodyDesc {
+        checkDominanceGroup(group);
         this.dominanceGroup = group;
         return this;
     }
@@ -1523,9 +2009,13 @@ export class RigidBodyDesc {
     /**
      * Sets whether or not the rigid-body is to be created asleep.
      *
-     * @param can - true if the rigid-body should be in sleep, default false.
+     * A rigid-body created asleep isn't simulated until it is woken up, either manually or by
+     * a contact with an awake rigid-body. Spawning many rigid-bodies asleep avoids the cost of
+     * simulating all of them at the first step after their insertion.
+     *
+     * @param sleeping - true if the rigid-body should be created asleep, default false.
      */
-    setSleeping(sleeping: boolean): RigidBodyDesc {
+    public setSleeping(sleeping: boolean): RigidBodyDesc {
         this.sleeping = sleeping;
         return this;
     }
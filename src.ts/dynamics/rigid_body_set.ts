@@ -170,6 +170,17 @@ export class RigidBodySet {
         this.map.delete(handle);
     }
 
+    /**
+     * Removes all the rigid-bodies from this set.
+     *
+     * All the rigid-body handles and objects obtained from this set become invalid. Prefer
+     * `World.clear` which also clears the other structures referencing them.
+     */
+    public clear() {
+        this.raw.clear();
+        this.map.clear();
+    }
+
     /**
      * The number of rigid-bodies on this set.
      */
@@ -186,6 +197,34 @@ export class RigidBodySet {
         return this.get(handle) != null;
     }
 
+    /**
+     * Inserts a copy of every rigid-body of another set into this set.
+     *
+     * The copies are inserted without colliders: use `ColliderSet.mergeFrom` with the returned
+     * remap table to copy these too. The `userData` of the rigid-bodies is shared with their
+     * copies. The other set is left unchanged.
+     *
+     * @param colliderSet - The set of colliders the copies will be attached to.
+     * @param other - The set of rigid-bodies to copy.
+     * @returns A map from the handles of the rigid-bodies of `other` to the handles of their copies.
+     */
+    public mergeFrom(
+        colliderSet: ColliderSet,
+        other: RigidBodySet,
+    ): Map<RigidBodyHandle, RigidBodyHandle> {
+        let rawRemap = this.raw.mergeFrom(other.raw);
+        let remap = new Map<RigidBodyHandle, RigidBodyHandle>();
+
+        for (let i = 0; i < rawRemap.length; i += 2) {
+            let body = new RigidBody(this.raw, colliderSet, rawRemap[i + 1]);
+            body.userData = other.get(rawRemap[i]).userData;
+            this.map.set(rawRemap[i + 1], body);
+            remap.set(rawRemap[i], rawRemap[i + 1]);
+        }
+
+        return remap;
+    }
+
     /**
      * Gets the rigid-body with the given handle.
      *
@@ -220,6 +259,111 @@ export class RigidBodySet {
         });
     }
 
+    /**
+     * The number of floats written per rigid-body by `writePositions` and `writeActivePositions`.
+     *
+     * In 2D, each position is written as the translation `(x, y)` followed by the rotation angle.
+     * In 3D, each position is written as the translation `(x, y, z)` followed by the rotation
+     * quaternion `(x, y, z, w)`.
+     */
+    public static positionStride(): number {
+        return RawRigidBodySet.positionStride();
+    }
+
+    /**
+     * Writes the handle and position of every rigid-body of this set into the given buffers,
+     * in a single call.
+     *
+     * The position of the rigid-body with handle `handles[i]` is written at
+     * `positions[i * stride]` where `stride` is `RigidBodySet.positionStride()`. Writing stops
+     * as soon as either buffer is full.
+     *
+     * @param handles - The buffer where the rigid-body handles are written.
+     * @param positions - The buffer where the rigid-body positions are written.
+     * @returns the number of rigid-bodies written.
+     */
+    public writePositions(
+        handles: Float64Array,
+        positions: Float32Array,
+    ): number {
+        return this.raw.writePositions(handles, positions);
+    }
+
+    /**
+     * Writes the handle and position of every active (non-sleeping) dynamic or kinematic
+     * rigid-body into the given buffers, in a single call.
+     *
+     * The buffers are laid out the same way as for `writePositions`.
+     *
+     * @param islands - The island manager tracking the active rigid-bodies.
+     * @param handles - The buffer where the rigid-body handles are written.
+     * @param positions - The buffer where the rigid-body positions are written.
+     * @returns the number of rigid-bodies written.
+     */
+    public writeActivePositions(
+        islands: IslandManager,
+        handles: Float64Array,
+        positions: Float32Array,
+    ): number {
+        return this.raw.writeActivePositions(islands.raw, handles, positions);
+    }
+
+    /**
+     * Sets the translation of many rigid-bodies with a single call.
+     *
+     * The translation of the rigid-body with handle `handles[i]` is read from
+     * `translations[i * dim]` where `dim` is 2 in 2D and 3 in 3D. Handles that don't
+     * match any rigid-body of this set are skipped.
+     *
+     * @param handles - The handles of the rigid-bodies to modify.
+     * @param translations - The new world-space translations of the rigid-bodies.
+     * @param wakeUp - should the rigid-bodies be automatically woken-up?
+     * @returns the number of rigid-bodies actually modified.
+     */
+    public setTranslations(
+        handles: Float64Array,
+        translations: Float32Array,
+        wakeUp: boolean,
+    ): number {
+        RigidBodySet.checkTranslationsLength(handles, translations);
+        return this.raw.setTranslations(handles, translations, wakeUp);
+    }
+
+    /**
+     * Sets the next kinematic translation of many kinematic rigid-bodies with a single call.
+     *
+     * The buffers are laid out the same way as for `setTranslations`. This has no effect on
+     * rigid-bodies that are not kinematic.
+     *
+     * @param handles - The handles of the rigid-bodies to modify.
+     * @param translations - The world-space translations of the rigid-bodies after the next timestep.
+     * @returns the number of rigid-bodies found in this set.
+     */
+    public setNextKinematicTranslations(
+        handles: Float64Array,
+        translations: Float32Array,
+    ): number {
+        RigidBodySet.checkTranslationsLength(handles, translations);
+        return this.raw.setNextKinematicTranslations(handles, translations);
+    }
+
+    private static checkTranslationsLength(
+        handles: Float64Array,
+        translations: Float32Array,
+    ) {
+        // #if DIM2
+        const dim = 2;
+        // #endif
+        // #if DIM3
+        const dim = 3;
+        // #endif
+
+        if (translations.length != handles.length * dim)
+            throw RangeError(
+                "The translations array must contain exactly one translation per handle.",
+            );
+    }
+
     /**
      * Gets all rigid-bodies in the list.
      *
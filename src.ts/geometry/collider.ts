@@ -15,6 +15,7 @@ import {
     ShapeType,
     Capsule,
     TriMesh,
+    TriMeshFlags,
     Polyline,
     Heightfield,
     Segment,
@@ -22,6 +23,8 @@ import {
     RoundTriangle,
     RoundCuboid,
     HalfSpace,
+    Compound,
+    ConvexDecomposition,
     // #if DIM2
     ConvexPolygon,
     RoundConvexPolygon,
@@ -36,14 +39,18 @@ import {
     // #endif
 } from "./shape";
 import {Ray, RayIntersection} from "./ray";
-import {PointProjection} from "./point";
+import {PointColliderProjection, PointProjection} from "./point";
 import {ShapeColliderTOI, ShapeTOI} from "./toi";
-import {ShapeContact} from "./contact";
+import {ClosestPoints, ShapeContact} from "./contact";
 import {ColliderSet} from "./collider_set";
 
+// NOTE: must match the bits in the ActiveCollisionTypes on the Rust side.
 /**
  * Flags affecting whether collision-detection happens between two colliders
  * depending on the type of rigid-bodies they are attached to.
+ *
+ * Collision-detection happens between two colliders if the pair of rigid-body types
+ * is enabled on at least one of them. The default is `ActiveCollisionTypes.DEFAULT`.
  */
 export enum ActiveCollisionTypes {
     /**
@@ -106,17 +113,20 @@ export type ColliderHandle = number;
  */
 export class Collider {
     private colliderSet: ColliderSet; // The Collider won't need to free this.
+    private bodySet: RigidBodySet; // The Collider won't need to free this.
     readonly handle: ColliderHandle;
     private _shape: Shape;
     private _parent: RigidBody | null;
 
     constructor(
         colliderSet: ColliderSet,
+        bodySet: RigidBodySet,
         handle: ColliderHandle,
         parent: RigidBody | null,
         shape?: Shape,
     ) {
         this.colliderSet = colliderSet;
+        this.bodySet = bodySet;
         this.handle = handle;
         this._parent = parent;
         this._shape = shape;
@@ -124,16 +134,17 @@ export class Collider {
 
     /** @internal */
     public finalizeDeserialization(bodies: RigidBodySet) {
+        this.bodySet = bodies;
         if (this.handle != null) {
             this._parent = bodies.get(
-                this.colliderSet.raw.coParent(this.handle),
+                this.checkedRawSet().coParent(this.handle),
             );
         }
     }
 
     private ensureShapeIsCached() {
         if (!this._shape)
-            this._shape = Shape.fromRaw(this.colliderSet.raw, this.handle);
+            this._shape = Shape.fromRaw(this.checkedRawSet(), this.handle);
     }
 
     /**
@@ -152,12 +163,27 @@ export class Collider {
         return this.colliderSet.raw.contains(this.handle);
     }
 
+    // The handles are checked before calling into the raw set: an invalid handle
+    // throwing from within WASM would leave the whole set borrowed, and unusable
+    // by any later call (including the simulation step).
+    private checkedRawSet(collider2?: Collider): RawColliderSet {
+        let rawSet = this.colliderSet.raw;
+        if (
+            !rawSet.contains(this.handle) ||
+            (!!collider2 && !rawSet.contains(collider2.handle))
+        )
+            throw Error(
+                "Invalid Collider reference. It may have been removed from the physics World.",
+            );
+        return rawSet;
+    }
+
     /**
      * The world-space translation of this rigid-body.
      */
     public translation(): Vector {
         return VectorOps.fromRaw(
-            this.colliderSet.raw.coTranslation(this.handle),
+            this.checkedRawSet().coTranslation(this.handle),
         );
     }
 
@@ -166,15 +192,53 @@ export class Collider {
      */
     public rotation(): Rotation {
         return RotationOps.fromRaw(
-            this.colliderSet.raw.coRotation(this.handle),
+            this.checkedRawSet().coRotation(this.handle),
         );
     }
 
+    /**
+     * The translation of this collider relative to its parent rigid-body.
+     *
+     * Together with `this.parent()`, this gives the local offset of a collider hit by a scene
+     * query on a rigid-body made of multiple colliders. Returns `null` if this collider isn't
+     * attached to a rigid-body.
+     */
+    public translationWrtParent(): Vector | null {
+        return VectorOps.fromRaw(
+            this.checkedRawSet().coTranslationWrtParent(this.handle),
+        );
+    }
+
+    /**
+     * The orientation of this collider relative to its parent rigid-body.
+     *
+     * Returns `null` if this collider isn't attached to a rigid-body.
+     */
+    public rotationWrtParent(): Rotation | null {
+        return RotationOps.fromRaw(
+            this.checkedRawSet().coRotationWrtParent(this.handle),
+        );
+    }
+
+    /**
+     * The lower bound of the world-space axis-aligned bounding box of this collider.
+     */
+    public aabbMin(): Vector {
+        return VectorOps.fromRaw(this.checkedRawSet().coAabbMin(this.handle));
+    }
+
+    /**
+     * The upper bound of the world-space axis-aligned bounding box of this collider.
+     */
+    public aabbMax(): Vector {
+        return VectorOps.fromRaw(this.checkedRawSet().coAabbMax(this.handle));
+    }
+
     /**
      * Is this collider a sensor?
      */
     public isSensor(): boolean {
-        return this.colliderSet.raw.coIsSensor(this.handle);
+        return this.checkedRawSet().coIsSensor(this.handle);
     }
 
     /**
@@ -182,34 +246,72 @@ export class Collider {
      * @param isSensor - If `true`, the collider will be a sensor.
      */
     public setSensor(isSensor: boolean) {
-        this.colliderSet.raw.coSetSensor(this.handle, isSensor);
+        this.checkedRawSet().coSetSensor(this.handle, isSensor);
     }
 
     /**
      * Sets the new shape of the collider.
+     *
+     * The collider keeps its other properties (material, collision groups, parent, and position).
+     * The mass-properties of its parent rigid-body are updated at the next physics step, or can be
+     * updated immediately with `RigidBody.recomputeMassPropertiesFromColliders`.
+     *
      * @param shape - The collider’s new shape.
      */
     public setShape(shape: Shape) {
         let rawShape = shape.intoRaw();
-        this.colliderSet.raw.coSetShape(this.handle, rawShape);
+        this.checkedRawSet().coSetShape(this.handle, rawShape);
         rawShape.free();
         this._shape = shape;
     }
 
+    /**
+     * Replaces the shape of this collider by a copy scaled along each of its local axes.
+     *
+     * Balls and capsules only support uniform scaling, and cylinders and cones (in 3D) require
+     * the same factor along their `x` and `z` axes. Round shapes, half-spaces, and compound
+     * shapes can't be scaled.
+     *
+     * @param scale - The scaling factor along each local axis of the shape. Each factor must be
+     *   a finite positive number.
+     * @returns `true` if the shape was scaled, `false` if it doesn’t support this scale (in which
+     *   case the collider is left unchanged).
+     */
+    public scaleShape(scale: Vector): boolean {
+        let rawShape = this.shape.intoRaw();
+        let rawScale = VectorOps.intoRaw(scale);
+        let scaled = rawShape.scaled(rawScale);
+        rawShape.free();
+        rawScale.free();
+
+        if (!scaled) {
+            return false;
+        }
+
+        this.checkedRawSet().coSetShape(this.handle, scaled);
+        scaled.free();
+        this._shape = null;
+        return true;
+    }
+
     /**
      * Sets whether this collider is enabled or not.
      *
+     * A disabled collider stays attached to its parent rigid-body but is ignored by collision
+     * detection and scene queries, e.g., to toggle a hitbox. Re-enabling it takes effect at the
+     * next physics step.
+     *
      * @param enabled - Set to `false` to disable this collider (its parent rigid-body won’t be disabled automatically by this).
      */
     public setEnabled(enabled: boolean) {
-        this.colliderSet.raw.coSetEnabled(this.handle, enabled);
+        this.checkedRawSet().coSetEnabled(this.handle, enabled);
     }
 
     /**
      * Is this collider enabled?
      */
     public isEnabled(): boolean {
-        return this.colliderSet.raw.coIsEnabled(this.handle);
+        return this.checkedRawSet().coIsEnabled(this.handle);
     }
 
     /**
@@ -220,7 +322,7 @@ export class Collider {
      *                   constraints solver).
      */
     public setRestitution(restitution: number) {
-        this.colliderSet.raw.coSetRestitution(this.handle, restitution);
+        this.checkedRawSet().coSetRestitution(this.handle, restitution);
     }
 
     /**
@@ -231,43 +333,49 @@ export class Collider {
      *                   being built.
      */
     public setFriction(friction: number) {
-        this.colliderSet.raw.coSetFriction(this.handle, friction);
+        this.checkedRawSet().coSetFriction(this.handle, friction);
     }
 
     /**
      * Gets the rule used to combine the friction coefficients of two colliders
-     * colliders involved in a contact.
+     * involved in a contact.
+     *
+     * The rule actually applied to a contact is the one with the highest value among the
+     * rules of the two colliders, see `CoefficientCombineRule`.
      */
     public frictionCombineRule(): CoefficientCombineRule {
-        return this.colliderSet.raw.coFrictionCombineRule(this.handle);
+        return this.checkedRawSet().coFrictionCombineRule(this.handle);
     }
 
     /**
      * Sets the rule used to combine the friction coefficients of two colliders
-     * colliders involved in a contact.
+     * involved in a contact.
      *
      * @param rule − The combine rule to apply.
      */
     public setFrictionCombineRule(rule: CoefficientCombineRule) {
-        this.colliderSet.raw.coSetFrictionCombineRule(this.handle, rule);
+        this.checkedRawSet().coSetFrictionCombineRule(this.handle, rule);
     }
 
     /**
      * Gets the rule used to combine the restitution coefficients of two colliders
-     * colliders involved in a contact.
+     * involved in a contact.
+     *
+     * The rule actually applied to a contact is the one with the highest value among the
+     * rules of the two colliders, see `CoefficientCombineRule`.
      */
     public restitutionCombineRule(): CoefficientCombineRule {
-        return this.colliderSet.raw.coRestitutionCombineRule(this.handle);
+        return this.checkedRawSet().coRestitutionCombineRule(this.handle);
     }
 
     /**
      * Sets the rule used to combine the restitution coefficients of two colliders
-     * colliders involved in a contact.
+     * involved in a contact.
      *
      * @param rule − The combine rule to apply.
      */
     public setRestitutionCombineRule(rule: CoefficientCombineRule) {
-        this.colliderSet.raw.coSetRestitutionCombineRule(this.handle, rule);
+        this.checkedRawSet().coSetRestitutionCombineRule(this.handle, rule);
     }
 
     /**
@@ -279,7 +387,7 @@ export class Collider {
      * @param groups - The collision groups used for the collider being built.
      */
     public setCollisionGroups(groups: InteractionGroups) {
-        this.colliderSet.raw.coSetCollisionGroups(this.handle, groups);
+        this.checkedRawSet().coSetCollisionGroups(this.handle, groups);
     }
 
     /**
@@ -292,14 +400,14 @@ export class Collider {
      * @param groups - The solver groups used for the collider being built.
      */
     public setSolverGroups(groups: InteractionGroups) {
-        this.colliderSet.raw.coSetSolverGroups(this.handle, groups);
+        this.checkedRawSet().coSetSolverGroups(this.handle, groups);
     }
 
     /**
      * Get the physics hooks active for this collider.
      */
     public activeHooks() {
-        return this.colliderSet.raw.coActiveHooks(this.handle);
+        return this.checkedRawSet().coActiveHooks(this.handle);
     }
 
     /**
@@ -310,14 +418,14 @@ export class Collider {
      * @param activeHooks - The hooks active for contact/intersection pairs involving this collider.
      */
     public setActiveHooks(activeHooks: ActiveHooks) {
-        this.colliderSet.raw.coSetActiveHooks(this.handle, activeHooks);
+        this.checkedRawSet().coSetActiveHooks(this.handle, activeHooks);
     }
 
     /**
      * The events active for this collider.
      */
     public activeEvents(): ActiveEvents {
-        return this.colliderSet.raw.coActiveEvents(this.handle);
+        return this.checkedRawSet().coActiveEvents(this.handle);
     }
 
     /**
@@ -328,14 +436,14 @@ export class Collider {
      * @param activeEvents - The events active for contact/intersection pairs involving this collider.
      */
     public setActiveEvents(activeEvents: ActiveEvents) {
-        this.colliderSet.raw.coSetActiveEvents(this.handle, activeEvents);
+        this.checkedRawSet().coSetActiveEvents(this.handle, activeEvents);
     }
 
     /**
      * Gets the collision types active for this collider.
      */
     public activeCollisionTypes(): ActiveCollisionTypes {
-        return this.colliderSet.raw.coActiveCollisionTypes(this.handle);
+        return this.checkedRawSet().coActiveCollisionTypes(this.handle);
     }
 
     /**
@@ -344,17 +452,31 @@ export class Collider {
      * @param threshold - The new force threshold.
      */
     public setContactForceEventThreshold(threshold: number) {
-        return this.colliderSet.raw.coSetContactForceEventThreshold(
+        return this.checkedRawSet().coSetContactForceEventThreshold(
             this.handle,
             threshold,
         );
     }
 
+    /**
+     * Enables the contact force events of this collider, with the given force threshold.
+     *
+     * This adds `ActiveEvents.CONTACT_FORCE_EVENTS` to the active events of this collider,
+     * keeping the other ones, and sets its contact force event threshold. This is the
+     * configuration needed, e.g., for projectiles reporting the strength of their impacts.
+     *
+     * @param threshold - The total force magnitude beyond which a contact force event can be
+     *   emitted.
+     */
+    public enableContactForceEvents(threshold: number) {
+        this.checkedRawSet().coEnableContactForceEvents(this.handle, threshold);
+    }
+
     /**
      * The total force magnitude beyond which a contact force event can be emitted.
      */
     public contactForceEventThreshold(): number {
-        return this.colliderSet.raw.coContactForceEventThreshold(this.handle);
+        return this.checkedRawSet().coContactForceEventThreshold(this.handle);
     }
 
     /**
@@ -363,7 +485,7 @@ export class Collider {
      * @param activeCollisionTypes - The hooks active for contact/intersection pairs involving this collider.
      */
     public setActiveCollisionTypes(activeCollisionTypes: ActiveCollisionTypes) {
-        this.colliderSet.raw.coSetActiveCollisionTypes(
+        this.checkedRawSet().coSetActiveCollisionTypes(
             this.handle,
             activeCollisionTypes,
         );
@@ -378,9 +500,14 @@ export class Collider {
      *
      * The mass and angular inertia of this collider will be computed automatically based on its
      * shape.
+     *
+     * @param density - The density to set, must be greater or equal to 0. A density of 0 makes this
+     *                  collider massless: it still collides, but doesn't affect the mass or angular
+     *                  inertia of the rigid-body it is attached to. This lets the body's mass be
+     *                  controlled by its other colliders or by `RigidBody.setAdditionalMass`.
      */
     public setDensity(density: number) {
-        this.colliderSet.raw.coSetDensity(this.handle, density);
+        this.checkedRawSet().coSetDensity(this.handle, density);
     }
 
     /**
@@ -394,7 +521,7 @@ export class Collider {
      * and this mass value.
      */
     public setMass(mass: number) {
-        this.colliderSet.raw.coSetMass(this.handle, mass);
+        this.checkedRawSet().coSetMass(this.handle, mass);
     }
 
     // #if DIM3
@@ -415,7 +542,7 @@ export class Collider {
         let rawPrincipalInertia = VectorOps.intoRaw(principalAngularInertia);
         let rawInertiaFrame = RotationOps.intoRaw(angularInertiaLocalFrame);
 
-        this.colliderSet.raw.coSetMassProperties(
+        this.checkedRawSet().coSetMassProperties(
             this.handle,
             mass,
             rawCom,
@@ -443,7 +570,7 @@ export class Collider {
         principalAngularInertia: number,
     ) {
         let rawCom = VectorOps.intoRaw(centerOfMass);
-        this.colliderSet.raw.coSetMassProperties(
+        this.checkedRawSet().coSetMassProperties(
             this.handle,
             mass,
             rawCom,
@@ -456,14 +583,28 @@ export class Collider {
     /**
      * Sets the translation of this collider.
      *
+     * If this collider is attached to a rigid-body, its position relative to that rigid-body is
+     * updated accordingly, so it doesn't snap back to its previous offset at the next step.
+     *
      * @param tra - The world-space position of the collider.
      */
     public setTranslation(tra: Vector) {
         // #if DIM2
-        this.colliderSet.raw.coSetTranslation(this.handle, tra.x, tra.y);
+        this.checkedRawSet().coSetTranslation(
+            this.handle,
+            tra.x,
+            tra.y,
+            this.bodySet.raw,
+        );
         // #endif
         // #if DIM3
-        this.colliderSet.raw.coSetTranslation(this.handle, tra.x, tra.y, tra.z);
+        this.checkedRawSet().coSetTranslation(
+            this.handle,
+            tra.x,
+            tra.y,
+            tra.z,
+            this.bodySet.raw,
+        );
         // #endif
     }
 
@@ -476,14 +617,14 @@ export class Collider {
      */
     public setTranslationWrtParent(tra: Vector) {
         // #if DIM2
-        this.colliderSet.raw.coSetTranslationWrtParent(
+        this.checkedRawSet().coSetTranslationWrtParent(
             this.handle,
             tra.x,
             tra.y,
         );
         // #endif
         // #if DIM3
-        this.colliderSet.raw.coSetTranslationWrtParent(
+        this.checkedRawSet().coSetTranslationWrtParent(
             this.handle,
             tra.x,
             tra.y,
@@ -496,17 +637,21 @@ export class Collider {
     /**
      * Sets the rotation quaternion of this collider.
      *
+     * If this collider is attached to a rigid-body, its position relative to that rigid-body is
+     * updated accordingly, so it doesn't snap back to its previous offset at the next step.
+     *
      * This does nothing if a zero quaternion is provided.
      *
      * @param rotation - The rotation to set.
      */
     public setRotation(rot: Rotation) {
-        this.colliderSet.raw.coSetRotation(
+        this.checkedRawSet().coSetRotation(
             this.handle,
             rot.x,
             rot.y,
             rot.z,
             rot.w,
+            this.bodySet.raw,
         );
     }
 
@@ -519,7 +664,7 @@ export class Collider {
      * @param rotation - The rotation to set.
      */
     public setRotationWrtParent(rot: Rotation) {
-        this.colliderSet.raw.coSetRotationWrtParent(
+        this.checkedRawSet().coSetRotationWrtParent(
             this.handle,
             rot.x,
             rot.y,
@@ -532,10 +677,17 @@ export class Collider {
     /**
      * Sets the rotation angle of this collider.
      *
+     * If this collider is attached to a rigid-body, its position relative to that rigid-body is
+     * updated accordingly, so it doesn't snap back to its previous offset at the next step.
+     *
      * @param angle - The rotation angle, in radians.
      */
     public setRotation(angle: number) {
-        this.colliderSet.raw.coSetRotation(this.handle, angle);
+        this.checkedRawSet().coSetRotation(
+            this.handle,
+            angle,
+            this.bodySet.raw,
+        );
     }
 
     /**
@@ -546,7 +698,7 @@ export class Collider {
      * @param angle - The rotation angle, in radians.
      */
     public setRotationWrtParent(angle: number) {
-        this.colliderSet.raw.coSetRotationWrtParent(this.handle, angle);
+        this.checkedRawSet().coSetRotationWrtParent(this.handle, angle);
     }
     // #endif
 
@@ -555,7 +707,7 @@ export class Collider {
      * @deprecated this field will be removed in the future, please access this field on `shape` member instead.
      */
     public shapeType(): ShapeType {
-        return this.colliderSet.raw.coShapeType(this.handle);
+        return this.checkedRawSet().coShapeType(this.handle);
     }
 
     /**
@@ -564,7 +716,7 @@ export class Collider {
      */
     public halfExtents(): Vector {
         return VectorOps.fromRaw(
-            this.colliderSet.raw.coHalfExtents(this.handle),
+            this.checkedRawSet().coHalfExtents(this.handle),
         );
     }
 
@@ -575,7 +727,7 @@ export class Collider {
      */
     public setHalfExtents(newHalfExtents: Vector) {
         const rawPoint = VectorOps.intoRaw(newHalfExtents);
-        this.colliderSet.raw.coSetHalfExtents(this.handle, rawPoint);
+        this.checkedRawSet().coSetHalfExtents(this.handle, rawPoint);
     }
 
     /**
@@ -583,7 +735,7 @@ export class Collider {
      * @deprecated this field will be removed in the future, please access this field on `shape` member instead.
      */
     public radius(): number {
-        return this.colliderSet.raw.coRadius(this.handle);
+        return this.checkedRawSet().coRadius(this.handle);
     }
 
     /**
@@ -592,15 +744,18 @@ export class Collider {
      * @param newRadius - desired radius.
      */
     public setRadius(newRadius: number): void {
-        this.colliderSet.raw.coSetRadius(this.handle, newRadius);
+        this.checkedRawSet().coSetRadius(this.handle, newRadius);
     }
 
     /**
-     * The radius of the round edges of this collider if it is a round cylinder.
+     * The radius of the round edges of this collider, or `undefined` if its shape has no round edges.
+     *
+     * Rounded shapes are inflated by this radius, so renderers should inflate the drawn geometry by
+     * the same amount to match the collision surface.
      * @deprecated this field will be removed in the future, please access this field on `shape` member instead.
      */
     public roundRadius(): number {
-        return this.colliderSet.raw.coRoundRadius(this.handle);
+        return this.checkedRawSet().coRoundRadius(this.handle);
     }
 
     /**
@@ -609,7 +764,7 @@ export class Collider {
      * @param newBorderRadius - desired round edge radius.
      */
     public setRoundRadius(newBorderRadius: number) {
-        this.colliderSet.raw.coSetRoundRadius(this.handle, newBorderRadius);
+        this.checkedRawSet().coSetRoundRadius(this.handle, newBorderRadius);
     }
 
     /**
@@ -617,7 +772,7 @@ export class Collider {
      * @deprecated this field will be removed in the future, please access this field on `shape` member instead.
      */
     public halfHeight(): number {
-        return this.colliderSet.raw.coHalfHeight(this.handle);
+        return this.checkedRawSet().coHalfHeight(this.handle);
     }
 
     /**
@@ -626,7 +781,7 @@ export class Collider {
      * @param newHalfheight - desired half height.
      */
     public setHalfHeight(newHalfheight: number) {
-        this.colliderSet.raw.coSetHalfHeight(this.handle, newHalfheight);
+        this.checkedRawSet().coSetHalfHeight(this.handle, newHalfheight);
     }
 
     /**
@@ -635,7 +790,7 @@ export class Collider {
      * @deprecated this field will be removed in the future, please access this field on `shape` member instead.
      */
     public vertices(): Float32Array {
-        return this.colliderSet.raw.coVertices(this.handle);
+        return this.checkedRawSet().coVertices(this.handle);
     }
 
     /**
@@ -644,7 +799,7 @@ export class Collider {
      * @deprecated this field will be removed in the future, please access this field on `shape` member instead.
      */
     public indices(): Uint32Array | undefined {
-        return this.colliderSet.raw.coIndices(this.handle);
+        return this.checkedRawSet().coIndices(this.handle);
     }
 
     /**
@@ -654,7 +809,7 @@ export class Collider {
      * @deprecated this field will be removed in the future, please access this field on `shape` member instead.
      */
     public heightfieldHeights(): Float32Array {
-        return this.colliderSet.raw.coHeightfieldHeights(this.handle);
+        return this.checkedRawSet().coHeightfieldHeights(this.handle);
     }
 
     /**
@@ -663,7 +818,7 @@ export class Collider {
      * @deprecated this field will be removed in the future, please access this field on `shape` member instead.
      */
     public heightfieldScale(): Vector {
-        let scale = this.colliderSet.raw.coHeightfieldScale(this.handle);
+        let scale = this.checkedRawSet().coHeightfieldScale(this.handle);
         return VectorOps.fromRaw(scale);
     }
 
@@ -674,7 +829,7 @@ export class Collider {
      * @deprecated this field will be removed in the future, please access this field on `shape` member instead.
      */
     public heightfieldNRows(): number {
-        return this.colliderSet.raw.coHeightfieldNRows(this.handle);
+        return this.checkedRawSet().coHeightfieldNRows(this.handle);
     }
 
     /**
@@ -683,9 +838,132 @@ export class Collider {
      * @deprecated this field will be removed in the future, please access this field on `shape` member instead.
      */
     public heightfieldNCols(): number {
-        return this.colliderSet.raw.coHeightfieldNCols(this.handle);
+        return this.checkedRawSet().coHeightfieldNCols(this.handle);
+    }
+
+    /**
+     * If this collider has a heightfield shape, this returns the height at the
+     * `i`-th row and `j`-th column of its height matrix.
+     *
+     * @returns `undefined` if this isn't a heightfield or if `(i, j)` is out of bounds.
+     */
+    public heightfieldHeight(i: number, j: number): number | undefined {
+        return this.checkedRawSet().coHeightfieldHeight(this.handle, i, j);
+    }
+
+    /**
+     * If this collider has a heightfield shape, sets the height at the `i`-th row
+     * and `j`-th column of its height matrix.
+     *
+     * Unlike replacing the collider, this keeps the collider handle and doesn't transfer the
+     * whole height buffer from JavaScript. The scale and cell statuses of the heightfield are
+     * kept, and its broad-phase bounding volume is updated at the next step. However, the
+     * heightfield is rebuilt on each call, which costs as much as creating it: to change several
+     * heights, e.g., to dig a crater into a terrain, use `this.setHeightfieldHeights` instead.
+     *
+     * @returns `false` if this isn't a heightfield or if `(i, j)` is out of bounds.
+     */
+    public setHeightfieldHeight(i: number, j: number, height: number): boolean {
+        const updated = this.checkedRawSet().coHeightfieldSetHeight(
+            this.handle,
+            i,
+            j,
+            height,
+        );
+        if (updated) this._shape = null;
+        return updated;
     }
 
+    /**
+     * If this collider has a heightfield shape, sets the heights of a rectangular patch of its
+     * height matrix.
+     *
+     * The heightfield is rebuilt once for the whole patch, at the cost of creating it, so this
+     * is much cheaper than calling `this.setHeightfieldHeight` for each height of the patch.
+     * The scale and cell statuses of the heightfield are kept, and its broad-phase bounding
+     * volume is updated at the next step.
+     *
+     * @param i - The row of the first height of the patch.
+     * @param j - The column of the first height of the patch.
+     * @param nrows - The number of rows of the patch.
+     * @param heights - The heights of the patch, in column-major order like when creating the
+     *   heightfield. The patch spans `heights.length / nrows` columns.
+     * @returns `false`, without changing anything, if this isn't a heightfield or if the patch
+     *   is out of bounds.
+     */
+    public setHeightfieldHeights(
+        i: number,
+        j: number,
+        nrows: number,
+        heights: Float32Array,
+    ): boolean {
+        const updated = this.checkedRawSet().coHeightfieldSetHeights(
+            this.handle,
+            i,
+            j,
+            nrows,
+            heights,
+        );
+        if (updated) this._shape = null;
+        return updated;
+    }
+    // #endif
+
+    // #if DIM2
+    /**
+     * If this collider has a heightfield shape, this returns the height of its
+     * `i`-th point.
+     *
+     * @returns `undefined` if this isn't a heightfield or if `i` is out of bounds.
+     */
+    public heightfieldHeight(i: number): number | undefined {
+        return this.checkedRawSet().coHeightfieldHeight(this.handle, i);
+    }
+
+    /**
+     * If this collider has a heightfield shape, sets the height of its `i`-th point.
+     *
+     * Unlike replacing the collider, this keeps the collider handle and doesn't transfer the
+     * whole height buffer from JavaScript. The scale and removed segments of the heightfield are
+     * kept, and its broad-phase bounding volume is updated at the next step. However, the
+     * heightfield is rebuilt on each call, which costs as much as creating it: to change several
+     * heights, e.g., to dig a crater into a terrain, use `this.setHeightfieldHeights` instead.
+     *
+     * @returns `false` if this isn't a heightfield or if `i` is out of bounds.
+     */
+    public setHeightfieldHeight(i: number, height: number): boolean {
+        const updated = this.checkedRawSet().coHeightfieldSetHeight(
+            this.handle,
+            i,
+            height,
+        );
+        if (updated) this._shape = null;
+        return updated;
+    }
+
+    /**
+     * If this collider has a heightfield shape, sets the heights of its consecutive points
+     * starting at the `i`-th one.
+     *
+     * The heightfield is rebuilt once for the whole batch, at the cost of creating it, so this
+     * is much cheaper than calling `this.setHeightfieldHeight` for each point. The scale and
+     * removed segments of the heightfield are kept, and its broad-phase bounding volume is
+     * updated at the next step.
+     *
+     * @param i - The index of the first point to change.
+     * @param heights - The new heights of the points `i` to `i + heights.length - 1`.
+     * @returns `false`, without changing anything, if this isn't a heightfield or if a point is
+     *   out of bounds.
+     */
+    public setHeightfieldHeights(i: number, heights: Float32Array): boolean {
+        const updated = this.checkedRawSet().coHeightfieldSetHeights(
+            this.handle,
+            i,
+            heights,
+        );
+        if (updated) this._shape = null;
+        return updated;
+    }
     // #endif
 
     /**
@@ -699,49 +977,55 @@ export class Collider {
      * The friction coefficient of this collider.
      */
     public friction(): number {
-        return this.colliderSet.raw.coFriction(this.handle);
+        return this.checkedRawSet().coFriction(this.handle);
     }
 
     /**
      * The restitution coefficient of this collider.
      */
     public restitution(): number {
-        return this.colliderSet.raw.coRestitution(this.handle);
+        return this.checkedRawSet().coRestitution(this.handle);
     }
 
     /**
      * The density of this collider.
+     *
+     * If the mass of this collider was set explicitly, this is the density derived from that
+     * mass and the collider's volume.
      */
     public density(): number {
-        return this.colliderSet.raw.coDensity(this.handle);
+        return this.checkedRawSet().coDensity(this.handle);
     }
 
     /**
-     * The mass of this collider.
+     * The mass this collider contributes to the rigid-body it is attached to.
+     *
+     * The total mass of the rigid-body, given by `RigidBody.mass`, is the sum of the masses of
+     * all its colliders plus its additional mass. This is zero for colliders with a density of zero.
      */
     public mass(): number {
-        return this.colliderSet.raw.coMass(this.handle);
+        return this.checkedRawSet().coMass(this.handle);
     }
 
     /**
      * The volume of this collider.
      */
     public volume(): number {
-        return this.colliderSet.raw.coVolume(this.handle);
+        return this.checkedRawSet().coVolume(this.handle);
     }
 
     /**
      * The collision groups of this collider.
      */
     public collisionGroups(): InteractionGroups {
-        return this.colliderSet.raw.coCollisionGroups(this.handle);
+        return this.checkedRawSet().coCollisionGroups(this.handle);
     }
 
     /**
      * The solver groups of this collider.
      */
     public solverGroups(): InteractionGroups {
-        return this.colliderSet.raw.coSolverGroups(this.handle);
+        return this.checkedRawSet().coSolverGroups(this.handle);
     }
 
     /**
@@ -751,7 +1035,7 @@ export class Collider {
      */
     public containsPoint(point: Vector): boolean {
         let rawPoint = VectorOps.intoRaw(point);
-        let result = this.colliderSet.raw.coContainsPoint(
+        let result = this.checkedRawSet().coContainsPoint(
             this.handle,
             rawPoint,
         );
@@ -774,7 +1058,33 @@ export class Collider {
     public projectPoint(point: Vector, solid: boolean): PointProjection | null {
         let rawPoint = VectorOps.intoRaw(point);
         let result = PointProjection.fromRaw(
-            this.colliderSet.raw.coProjectPoint(this.handle, rawPoint, solid),
+            this.checkedRawSet().coProjectPoint(this.handle, rawPoint, solid),
+        );
+
+        rawPoint.free();
+
+        return result;
+    }
+
+    /**
+     * Find the projection of a point on the boundary of this collider, along with the
+     * geometric feature it was projected on.
+     *
+     * The returned `featureType` and `featureId` identify the vertex, edge (in 3D), or face the
+     * point was projected on, e.g., to snap objects to the edges of a mesh. The feature id uses
+     * the same indexing as the shape itself, e.g., the triangle index for the faces of a
+     * triangle mesh.
+     *
+     * @param point - The point to project.
+     */
+    public projectPointAndGetFeature(point: Vector): PointColliderProjection {
+        let rawPoint = VectorOps.intoRaw(point);
+        let result = PointColliderProjection.fromRaw(
+            this.colliderSet,
+            this.checkedRawSet().coProjectPointAndGetFeature(
+                this.handle,
+                rawPoint,
+            ),
         );
 
         rawPoint.free();
@@ -792,7 +1102,7 @@ export class Collider {
     public intersectsRay(ray: Ray, maxToi: number): boolean {
         let rawOrig = VectorOps.intoRaw(ray.origin);
         let rawDir = VectorOps.intoRaw(ray.dir);
-        let result = this.colliderSet.raw.coIntersectsRay(
+        let result = this.checkedRawSet().coIntersectsRay(
             this.handle,
             rawOrig,
             rawDir,
@@ -836,7 +1146,7 @@ export class Collider {
 
         let result = ShapeTOI.fromRaw(
             this.colliderSet,
-            this.colliderSet.raw.coCastShape(
+            this.checkedRawSet().coCastShape(
                 this.handle,
                 rawCollider1Vel,
                 rawShape2,
@@ -881,7 +1191,7 @@ export class Collider {
 
         let result = ShapeColliderTOI.fromRaw(
             this.colliderSet,
-            this.colliderSet.raw.coCastCollider(
+            this.checkedRawSet(collider2).coCastCollider(
                 this.handle,
                 rawCollider1Vel,
                 collider2.handle,
@@ -906,7 +1216,7 @@ export class Collider {
         let rawRot2 = RotationOps.intoRaw(shapeRot2);
         let rawShape2 = shape2.intoRaw();
 
-        let result = this.colliderSet.raw.coIntersectsShape(
+        let result = this.checkedRawSet().coIntersectsShape(
             this.handle,
             rawShape2,
             rawPos2,
@@ -940,7 +1250,7 @@ export class Collider {
         let rawShape2 = shape2.intoRaw();
 
         let result = ShapeContact.fromRaw(
-            this.colliderSet.raw.coContactShape(
+            this.checkedRawSet().coContactShape(
                 this.handle,
                 rawShape2,
                 rawPos2,
@@ -968,7 +1278,7 @@ export class Collider {
         prediction: number,
     ): ShapeContact | null {
         let result = ShapeContact.fromRaw(
-            this.colliderSet.raw.coContactCollider(
+            this.checkedRawSet(collider2).coContactCollider(
                 this.handle,
                 collider2.handle,
                 prediction,
@@ -978,6 +1288,25 @@ export class Collider {
         return result;
     }
 
+    /**
+     * Computes the closest points between this collider and the given collider.
+     *
+     * @param collider2 - The second collider.
+     * @param maxDist - If the colliders are separated by a distance greater than this value, the
+     *   closest points aren't computed and the result has the `Disjoint` status.
+     * @returns `null` if the closest points aren't supported for this pair of shapes, otherwise
+     *   the closest points. The result is given in world-space.
+     */
+    closestPoints(collider2: Collider, maxDist: number): ClosestPoints | null {
+        return ClosestPoints.fromRaw(
+            this.checkedRawSet(collider2).coClosestPoints(
+                this.handle,
+                collider2.handle,
+                maxDist,
+            ),
+        );
+    }
+
     /*
      * Find the closest intersection between a ray and this collider.
      *
@@ -993,7 +1322,7 @@ export class Collider {
     public castRay(ray: Ray, maxToi: number, solid: boolean): number {
         let rawOrig = VectorOps.intoRaw(ray.origin);
         let rawDir = VectorOps.intoRaw(ray.dir);
-        let result = this.colliderSet.raw.coCastRay(
+        let result = this.checkedRawSet().coCastRay(
             this.handle,
             rawOrig,
             rawDir,
@@ -1026,7 +1355,7 @@ export class Collider {
         let rawOrig = VectorOps.intoRaw(ray.origin);
         let rawDir = VectorOps.intoRaw(ray.dir);
         let result = RayIntersection.fromRaw(
-            this.colliderSet.raw.coCastRayAndGetNormal(
+            this.checkedRawSet().coCastRayAndGetNormal(
                 this.handle,
                 rawOrig,
                 rawDir,
@@ -1077,6 +1406,22 @@ export class ColliderDesc {
     activeCollisionTypes: ActiveCollisionTypes;
     contactForceEventThreshold: number;
 
+    /**
+     * The friction coefficient given to every collider descriptor created afterwards.
+     *
+     * This lets a whole application use the same material without calling `setFriction` on
+     * each descriptor. `setFriction` still overrides it for a single collider.
+     */
+    public static defaultFriction = 0.5;
+
+    /**
+     * The restitution coefficient given to every collider descriptor created afterwards.
+     *
+     * This lets a whole application use the same material without calling `setRestitution` on
+     * each descriptor. `setRestitution` still overrides it for a single collider.
+     */
+    public static defaultRestitution = 0.0;
+
     /**
      * Initializes a collider descriptor from the collision shape.
      *
@@ -1087,8 +1432,8 @@ export class ColliderDesc {
         this.shape = shape;
         this.massPropsMode = MassPropsMode.Density;
         this.density = 1.0;
-        this.friction = 0.5;
-        this.restitution = 0.0;
+        this.friction = ColliderDesc.defaultFriction;
+        this.restitution = ColliderDesc.defaultRestitution;
         this.rotation = RotationOps.identity();
         this.translation = VectorOps.zeros();
         this.isSensor = false;
@@ -1191,17 +1536,60 @@ export class ColliderDesc {
         return new ColliderDesc(shape);
     }
 
+    /**
+     * Creates a new collider descriptor with a compound shape.
+     *
+     * @param shapes - The parts of the compound shape.
+     * @param translations - The translation of each part, relative to the collider.
+     * @param rotations - The rotation of each part, relative to the collider.
+     */
+    public static compound(
+        shapes: Shape[],
+        translations: Vector[],
+        rotations: Rotation[],
+    ): ColliderDesc {
+        const shape = new Compound(shapes, translations, rotations);
+        return new ColliderDesc(shape);
+    }
+
+    /**
+     * Creates a new collider descriptor with a compound shape computed by decomposing a
+     * concave mesh into convex parts.
+     *
+     * @param vertices - The coordinates of the mesh's vertices.
+     * @param indices - The indices of the mesh's segments (in 2D) or triangles (in 3D).
+     * @param resolution - The voxelization resolution used by the decomposition.
+     * @param concavity - The maximum concavity allowed for each convex part.
+     */
+    public static convexDecomposition(
+        vertices: Float32Array,
+        indices: Uint32Array,
+        resolution?: number,
+        concavity?: number,
+    ): ColliderDesc {
+        const shape = new ConvexDecomposition(
+            vertices,
+            indices,
+            resolution,
+            concavity,
+        );
+        return new ColliderDesc(shape);
+    }
+
     /**
      * Creates a new collider descriptor with a triangle mesh shape.
      *
      * @param vertices - The coordinates of the triangle mesh's vertices.
      * @param indices - The indices of the triangle mesh's triangles.
+     * @param flags - (optional) The pre-processing applied to the triangle mesh. Defaults to
+     *   `TriMeshFlags.MERGE_DUPLICATE_VERTICES`.
      */
     public static trimesh(
         vertices: Float32Array,
         indices: Uint32Array,
+        flags?: TriMeshFlags,
     ): ColliderDesc {
-        const shape = new TriMesh(vertices, indices);
+        const shape = new TriMesh(vertices, indices, flags);
         return new ColliderDesc(shape);
     }
 
@@ -1657,7 +2045,7 @@ export class ColliderDesc {
 
     /**
      * Sets the rule used to combine the friction coefficients of two colliders
-     * colliders involved in a contact.
+     * involved in a contact.
      *
      * @param rule − The combine rule to apply.
      */
@@ -1668,7 +2056,7 @@ export class ColliderDesc {
 
     /**
      * Sets the rule used to combine the restitution coefficients of two colliders
-     * colliders involved in a contact.
+     * involved in a contact.
      *
      * @param rule − The combine rule to apply.
      */
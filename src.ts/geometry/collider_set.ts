@@ -1,5 +1,5 @@
 import {RawColliderSet} from "../raw";
-import {Coarena} from "../coarena";
+import {Coarena, flattenRemap} from "../coarena";
 import {RotationOps, VectorOps} from "../math";
 import {Collider, ColliderDesc, ColliderHandle} from "./collider";
 import {ImpulseJointHandle, IslandManager, RigidBodyHandle} from "../dynamics";
@@ -36,7 +36,7 @@ export class ColliderSet {
         // Initialize the map with the existing elements, if any.
         if (raw) {
             raw.forEachColliderHandle((handle: ColliderHandle) => {
-                this.map.set(handle, new Collider(this, handle, null));
+                this.map.set(handle, new Collider(this, null, handle, null));
             });
         }
     }
@@ -137,7 +137,7 @@ export class ColliderSet {
         // #endif
 
         let parent = hasParent ? bodies.get(parentHandle) : null;
-        let collider = new Collider(this, handle, parent, desc.shape);
+        let collider = new Collider(this, bodies, handle, parent, desc.shape);
         this.map.set(handle, collider);
         return collider;
     }
@@ -145,7 +145,12 @@ export class ColliderSet {
     /**
      * Remove a collider from this set.
      *
+     * The rigid-body the collider is attached to isn't removed. Its mass-properties are updated to
+     * account for its remaining colliders at the next physics step, or can be updated immediately
+     * with `RigidBody.recomputeMassPropertiesFromColliders`.
+     *
      * @param handle - The integer handle of the collider to remove.
+     * @param islands - The island manager of the world the collider is part of.
      * @param bodies - The set of rigid-body containing the rigid-body the collider is attached to.
      * @param wakeUp - If `true`, the rigid-body the removed collider is attached to will be woken-up automatically.
      */
@@ -167,6 +172,40 @@ export class ColliderSet {
         this.map.delete(handle);
     }
 
+    /**
+     * Inserts a copy of every collider of another set into this set.
+     *
+     * The other set is left unchanged.
+     *
+     * @param bodies - The set containing the copies of the parents of the colliders of `other`.
+     * @param other - The set of colliders to copy.
+     * @param bodyRemap - The remap table returned by `RigidBodySet.mergeFrom`. Colliders attached
+     *   to a rigid-body missing from this table are inserted without parent, at their current
+     *   world-space position.
+     * @returns A map from the handles of the colliders of `other` to the handles of their copies.
+     */
+    public mergeFrom(
+        bodies: RigidBodySet,
+        other: ColliderSet,
+        bodyRemap: Map<RigidBodyHandle, RigidBodyHandle>,
+    ): Map<ColliderHandle, ColliderHandle> {
+        let rawRemap = this.raw.mergeFrom(
+            other.raw,
+            bodies.raw,
+            flattenRemap(bodyRemap),
+        );
+        let remap = new Map<ColliderHandle, ColliderHandle>();
+
+        for (let i = 0; i < rawRemap.length; i += 2) {
+            let handle = rawRemap[i + 1];
+            let parent = bodies.get(this.raw.coParent(handle));
+            this.map.set(handle, new Collider(this, bodies, handle, parent));
+            remap.set(rawRemap[i], handle);
+        }
+
+        return remap;
+    }
+
     /**
      * Gets the rigid-body with the given handle.
      *
@@ -176,6 +215,17 @@ export class ColliderSet {
         return this.map.get(handle);
     }
 
+    /**
+     * Removes all the colliders from this set.
+     *
+     * All the collider handles and objects obtained from this set become invalid. Prefer
+     * `World.clear` which also clears the other structures referencing them.
+     */
+    public clear() {
+        this.raw.clear();
+        this.map.clear();
+    }
+
     /**
      * The number of colliders on this set.
      */
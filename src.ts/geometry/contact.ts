@@ -1,5 +1,5 @@
 import {Vector, VectorOps} from "../math";
-import {RawShapeContact} from "../raw";
+import {RawClosestPoints, RawShapeContact} from "../raw";
 
 /**
  * The contact info between two shapes.
@@ -60,3 +60,74 @@ export class ShapeContact {
         return result;
     }
 }
+
+// NOTE: must match the RawClosestPointsStatus on the Rust side.
+/**
+ * The status of a closest-points query between two shapes.
+ */
+export enum ClosestPointsStatus {
+    /**
+     * The shapes are intersecting.
+     */
+    Intersecting = 0,
+    /**
+     * The shapes are separated by a distance smaller than the maximum distance.
+     */
+    WithinMargin = 1,
+    /**
+     * The shapes are separated by a distance greater than the maximum distance.
+     */
+    Disjoint = 2,
+}
+
+/**
+ * The closest points between two shapes.
+ */
+export class ClosestPoints {
+    /**
+     * Whether the shapes are intersecting, close, or far from each other.
+     */
+    status: ClosestPointsStatus;
+
+    /**
+     * The distance between the two closest points. This is 0 if the shapes are
+     * intersecting, and `null` if they are disjoint.
+     */
+    distance: number | null;
+
+    /**
+     * The closest point on the first shape, or `null` unless the status is `WithinMargin`.
+     */
+    point1: Vector | null;
+
+    /**
+     * The closest point on the second shape, or `null` unless the status is `WithinMargin`.
+     */
+    point2: Vector | null;
+
+    constructor(
+        status: ClosestPointsStatus,
+        distance: number | null,
+        point1: Vector | null,
+        point2: Vector | null,
+    ) {
+        this.status = status;
+        this.distance = distance;
+        this.point1 = point1;
+        this.point2 = point2;
+    }
+
+    public static fromRaw(raw: RawClosestPoints): ClosestPoints {
+        if (!raw) return null;
+
+        const distance = raw.distance();
+        const result = new ClosestPoints(
+            raw.status() as number as ClosestPointsStatus,
+            distance === undefined ? null : distance,
+            VectorOps.fromRaw(raw.point1()),
+            VectorOps.fromRaw(raw.point2()),
+        );
+        raw.free();
+        return result;
+    }
+}
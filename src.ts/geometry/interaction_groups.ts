@@ -16,3 +16,36 @@
  * ```
  */
 export type InteractionGroups = number;
+
+/**
+ * Packs the given memberships and filter into interaction groups.
+ *
+ * @param memberships - The 16-bit mask of the groups the collider is part of.
+ * @param filter - The 16-bit mask of the groups the collider can interact with.
+ */
+export function interactionGroups(
+    memberships: number,
+    filter: number,
+): InteractionGroups {
+    return (((memberships & 0xffff) << 16) | (filter & 0xffff)) >>> 0;
+}
+
+/**
+ * The 16-bit memberships of the given interaction groups.
+ *
+ * @param groups - The interaction groups to unpack.
+ */
+export function interactionGroupsMemberships(
+    groups: InteractionGroups,
+): number {
+    return (groups >>> 16) & 0xffff;
+}
+
+/**
+ * The 16-bit filter of the given interaction groups.
+ *
+ * @param groups - The interaction groups to unpack.
+ */
+export function interactionGroupsFilter(groups: InteractionGroups): number {
+    return groups & 0xffff;
+}
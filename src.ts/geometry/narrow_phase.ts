@@ -43,6 +43,10 @@ export class NarrowPhase {
     /**
      * Enumerates all the colliders intersecting the given colliders, assuming one of them
      * is a sensor.
+     *
+     * This reports both sensor-sensor and sensor-solid overlaps, as long as the pair is allowed
+     * by the active collision types of both colliders. Only pairs that are currently intersecting
+     * are reported.
      */
     public intersectionsWith(
         collider1: ColliderHandle,
@@ -97,6 +101,55 @@ export class NarrowPhase {
     ): boolean {
         return this.raw.intersection_pair(collider1, collider2);
     }
+
+    /**
+     * Returns `true` if `collider1` and `collider2` are touching, i.e., if they have at least
+     * one contact point with a distance smaller or equal to zero, as of the last step.
+     *
+     * This is cheaper than reading the contact manifolds with `this.contactPair` when only a
+     * yes/no answer is needed. Speculative contacts within the prediction distance are not
+     * considered touching.
+     *
+     * @param collider1 − The first collider.
+     * @param collider2 − The second collider.
+     */
+    public areInContact(
+        collider1: ColliderHandle,
+        collider2: ColliderHandle,
+    ): boolean {
+        return this.raw.areInContact(collider1, collider2);
+    }
+
+    /**
+     * The deepest penetration depth between `collider1` and `collider2`, as of the last step.
+     *
+     * This is read from their contact manifolds, and is zero if the colliders aren't
+     * penetrating each other, e.g., to scale a squash effect on impact.
+     *
+     * @param collider1 − The first collider.
+     * @param collider2 − The second collider.
+     */
+    public penetrationDepth(
+        collider1: ColliderHandle,
+        collider2: ColliderHandle,
+    ): number {
+        return this.raw.penetrationDepth(collider1, collider2);
+    }
+
+    /**
+     * The number of pairs of colliders with at least one active contact, as of the last step.
+     */
+    public numContactPairs(): number {
+        return this.raw.numContactPairs();
+    }
+
+    /**
+     * The number of pairs of colliders intersecting, assuming one of them is a sensor, as of
+     * the last step.
+     */
+    public numIntersectionPairs(): number {
+        return this.raw.numIntersectionPairs();
+    }
 }
 
 export class TempContactManifold {
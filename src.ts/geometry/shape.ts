@@ -1,5 +1,5 @@
 import {Vector, VectorOps, Rotation, RotationOps} from "../math";
-import {RawColliderSet, RawShape} from "../raw";
+import {RawColliderSet, RawCompoundShapeBuilder, RawShape} from "../raw";
 import {ShapeContact} from "./contact";
 import {PointProjection} from "./point";
 import {Ray, RayIntersection} from "./ray";
@@ -261,6 +261,168 @@ export abstract class Shape {
         return result;
     }
 
+    // #if DIM2
+    /**
+     * Computes the time of impact between two shapes moving with constant linear and angular velocities.
+     *
+     * Each shape rotates around its own local origin.
+     *
+     * @param shapePos1 - The initial position of this shape.
+     * @param shapeRot1 - The initial rotation of this shape.
+     * @param shapeLinvel1 - The linear velocity of this shape.
+     * @param shapeAngvel1 - The angular velocity of this shape.
+     * @param shape2 - The second moving shape.
+     * @param shapePos2 - The initial position of the second shape.
+     * @param shapeRot2 - The initial rotation of the second shape.
+     * @param shapeLinvel2 - The linear velocity of the second shape.
+     * @param shapeAngvel2 - The angular velocity of the second shape.
+     * @param startTime - The time at which the motion starts.
+     * @param endTime - The maximum time when the impact can happen.
+     * @param stopAtPenetration - If set to `false`, the shape-cast won’t immediately stop if
+     *   the shapes are penetrating at the start time **and** their motions are such that they
+     *   are on a path to exit that penetration state.
+     * @returns The time of impact and contact information if the two shapes collide between
+     *   `startTime` and `endTime`, `null` otherwise.
+     */
+    public castShapeNonlinear(
+        shapePos1: Vector,
+        shapeRot1: Rotation,
+        shapeLinvel1: Vector,
+        shapeAngvel1: number,
+        shape2: Shape,
+        shapePos2: Vector,
+        shapeRot2: Rotation,
+        shapeLinvel2: Vector,
+        shapeAngvel2: number,
+        startTime: number,
+        endTime: number,
+        stopAtPenetration: boolean,
+    ): ShapeTOI | null {
+        let rawPos1 = VectorOps.intoRaw(shapePos1);
+        let rawRot1 = RotationOps.intoRaw(shapeRot1);
+        let rawLinvel1 = VectorOps.intoRaw(shapeLinvel1);
+        let rawPos2 = VectorOps.intoRaw(shapePos2);
+        let rawRot2 = RotationOps.intoRaw(shapeRot2);
+        let rawLinvel2 = VectorOps.intoRaw(shapeLinvel2);
+
+        let rawShape1 = this.intoRaw();
+        let rawShape2 = shape2.intoRaw();
+
+        let result = ShapeTOI.fromRaw(
+            null,
+            rawShape1.castShapeNonlinear(
+                rawPos1,
+                rawRot1,
+                rawLinvel1,
+                shapeAngvel1,
+                rawShape2,
+                rawPos2,
+                rawRot2,
+                rawLinvel2,
+                shapeAngvel2,
+                startTime,
+                endTime,
+                stopAtPenetration,
+            ),
+        );
+
+        rawPos1.free();
+        rawRot1.free();
+        rawLinvel1.free();
+        rawPos2.free();
+        rawRot2.free();
+        rawLinvel2.free();
+
+        rawShape1.free();
+        rawShape2.free();
+
+        return result;
+    }
+    // #endif
+
+    // #if DIM3
+    /**
+     * Computes the time of impact between two shapes moving with constant linear and angular velocities.
+     *
+     * Each shape rotates around its own local origin.
+     *
+     * @param shapePos1 - The initial position of this shape.
+     * @param shapeRot1 - The initial rotation of this shape.
+     * @param shapeLinvel1 - The linear velocity of this shape.
+     * @param shapeAngvel1 - The angular velocity of this shape.
+     * @param shape2 - The second moving shape.
+     * @param shapePos2 - The initial position of the second shape.
+     * @param shapeRot2 - The initial rotation of the second shape.
+     * @param shapeLinvel2 - The linear velocity of the second shape.
+     * @param shapeAngvel2 - The angular velocity of the second shape.
+     * @param startTime - The time at which the motion starts.
+     * @param endTime - The maximum time when the impact can happen.
+     * @param stopAtPenetration - If set to `false`, the shape-cast won’t immediately stop if
+     *   the shapes are penetrating at the start time **and** their motions are such that they
+     *   are on a path to exit that penetration state.
+     * @returns The time of impact and contact information if the two shapes collide between
+     *   `startTime` and `endTime`, `null` otherwise.
+     */
+    public castShapeNonlinear(
+        shapePos1: Vector,
+        shapeRot1: Rotation,
+        shapeLinvel1: Vector,
+        shapeAngvel1: Vector,
+        shape2: Shape,
+        shapePos2: Vector,
+        shapeRot2: Rotation,
+        shapeLinvel2: Vector,
+        shapeAngvel2: Vector,
+        startTime: number,
+        endTime: number,
+        stopAtPenetration: boolean,
+    ): ShapeTOI | null {
+        let rawPos1 = VectorOps.intoRaw(shapePos1);
+        let rawRot1 = RotationOps.intoRaw(shapeRot1);
+        let rawLinvel1 = VectorOps.intoRaw(shapeLinvel1);
+        let rawAngvel1 = VectorOps.intoRaw(shapeAngvel1);
+        let rawPos2 = VectorOps.intoRaw(shapePos2);
+        let rawRot2 = RotationOps.intoRaw(shapeRot2);
+        let rawLinvel2 = VectorOps.intoRaw(shapeLinvel2);
+        let rawAngvel2 = VectorOps.intoRaw(shapeAngvel2);
+
+        let rawShape1 = this.intoRaw();
+        let rawShape2 = shape2.intoRaw();
+
+        let result = ShapeTOI.fromRaw(
+            null,
+            rawShape1.castShapeNonlinear(
+                rawPos1,
+                rawRot1,
+                rawLinvel1,
+                rawAngvel1,
+                rawShape2,
+                rawPos2,
+                rawRot2,
+                rawLinvel2,
+                rawAngvel2,
+                startTime,
+                endTime,
+                stopAtPenetration,
+            ),
+        );
+
+        rawPos1.free();
+        rawRot1.free();
+        rawLinvel1.free();
+        rawPos2.free();
+        rawRot2.free();
+        rawLinvel2.free();
+        rawAngvel1.free();
+        rawAngvel2.free();
+
+        rawShape1.free();
+        rawShape2.free();
+
+        return result;
+    }
+    // #endif
+
     /**
      * Tests if this shape intersects another shape.
      *
@@ -504,7 +666,7 @@ export enum ShapeType {
     Triangle = 5,
     TriMesh = 6,
     HeightField = 7,
-    // Compound = 8,
+    Compound = 8,
     ConvexPolygon = 9,
     RoundCuboid = 10,
     RoundTriangle = 11,
@@ -527,7 +689,7 @@ export enum ShapeType {
     Triangle = 5,
     TriMesh = 6,
     HeightField = 7,
-    // Compound = 8,
+    Compound = 8,
     ConvexPolyhedron = 9,
     Cylinder = 10,
     Cone = 11,
@@ -588,6 +750,10 @@ export class HalfSpace extends Shape {
         let n = VectorOps.intoRaw(this.normal);
         let result = RawShape.halfspace(n);
         n.free();
+
+        if (!result)
+            throw TypeError("The normal of a half-space must not be zero.");
+
         return result;
     }
 }
@@ -886,6 +1052,114 @@ export class RoundTriangle extends Shape {
     }
 }
 
+/**
+ * A compound of convex parts computed by decomposing a concave mesh.
+ */
+export class ConvexDecomposition extends Shape {
+    readonly type = ShapeType.Compound;
+
+    /**
+     * The coordinates of the mesh's vertices.
+     */
+    vertices: Float32Array;
+
+    /**
+     * The indices of the mesh's segments (in 2D) or triangles (in 3D).
+     */
+    indices: Uint32Array;
+
+    /**
+     * The voxelization resolution used by the decomposition.
+     */
+    resolution?: number;
+
+    /**
+     * The maximum concavity allowed for each convex part.
+     */
+    concavity?: number;
+
+    /**
+     * Creates a new shape by decomposing a concave mesh into convex parts.
+     *
+     * @param vertices - The coordinates of the mesh's vertices.
+     * @param indices - The indices of the mesh's segments (in 2D) or triangles (in 3D).
+     * @param resolution - The voxelization resolution. Higher values preserve more detail but
+     *   are slower to compute.
+     * @param concavity - The maximum concavity allowed for each convex part. Lower values
+     *   produce more parts.
+     */
+    constructor(
+        vertices: Float32Array,
+        indices: Uint32Array,
+        resolution?: number,
+        concavity?: number,
+    ) {
+        super();
+        this.vertices = vertices;
+        this.indices = indices;
+        this.resolution = resolution;
+        this.concavity = concavity;
+    }
+
+    public intoRaw(): RawShape {
+        let result = RawShape.convexDecomposition(
+            this.vertices,
+            this.indices,
+            this.resolution,
+            this.concavity,
+        );
+
+        if (!result)
+            throw TypeError(
+                "Invalid mesh for convex decomposition: the vertex or index buffer has an invalid length, or an index is out of bounds.",
+            );
+
+        return result;
+    }
+}
+
+// NOTE: must match the bits in the TriMeshFlags on the Rust side.
+/**
+ * Flags controlling the pre-processing applied to a triangle mesh when it is built.
+ *
+ * Meshes imported from real-world assets often contain duplicate vertices and degenerate
+ * triangles: the cleanup flags remove them before they can generate bad contacts. One-way
+ * triangles aren't supported by these flags: use the `modifySolverContacts` physics hook to
+ * discard the contacts of one-way platforms instead.
+ */
+export enum TriMeshFlags {
+    /**
+     * Computes the half-edge topology of the mesh.
+     */
+    HALF_EDGE_TOPOLOGY = 0b0000_0001,
+    /**
+     * Computes the connected components of the mesh.
+     */
+    CONNECTED_COMPONENTS = 0b0000_0010,
+    /**
+     * Removes the triangles that would prevent computing the half-edge topology, i.e., triangles
+     * sharing an edge with more than one other triangle, or with an inconsistent orientation.
+     */
+    DELETE_BAD_TOPOLOGY_TRIANGLES = 0b0000_0100,
+    /**
+     * Assumes the mesh is closed and consistently oriented, so points inside of it are detected
+     * as such by point projections and contacts.
+     */
+    ORIENTED = 0b0000_1000,
+    /**
+     * Merges the vertices with identical positions.
+     */
+    MERGE_DUPLICATE_VERTICES = 0b0001_0000,
+    /**
+     * Removes the triangles with a zero area.
+     */
+    DELETE_DEGENERATE_TRIANGLES = 0b0010_0000,
+    /**
+     * Removes the triangles duplicating another triangle.
+     */
+    DELETE_DUPLICATE_TRIANGLES = 0b0100_0000,
+}
+
 /**
  * A shape that is a triangle mesh.
  */
@@ -916,7 +1190,87 @@ export class Polyline extends Shape {
     }
 
     public intoRaw(): RawShape {
-        return RawShape.polyline(this.vertices, this.indices);
+        let result = RawShape.polyline(this.vertices, this.indices);
+
+        if (!result)
+            throw TypeError(
+                "Invalid polyline: the vertex or index buffer has an invalid length, or an index is out of bounds.",
+            );
+
+        return result;
+    }
+}
+
+/**
+ * A shape made of several other shapes, each with its own position relative to the compound.
+ */
+export class Compound extends Shape {
+    readonly type = ShapeType.Compound;
+
+    /**
+     * The parts of this compound shape.
+     */
+    shapes: Shape[];
+
+    /**
+     * The translation of each part, relative to the compound shape's local frame.
+     */
+    translations: Vector[];
+
+    /**
+     * The rotation of each part, relative to the compound shape's local frame.
+     */
+    rotations: Rotation[];
+
+    /**
+     * Creates a new compound shape.
+     *
+     * @param shapes - The parts of the compound shape. They must not be compound shapes,
+     *   triangle meshes, polylines, or heightfields themselves.
+     * @param translations - The translation of each part.
+     * @param rotations - The rotation of each part.
+     */
+    constructor(
+        shapes: Shape[],
+        translations: Vector[],
+        rotations: Rotation[],
+    ) {
+        super();
+        this.shapes = shapes;
+        this.translations = translations;
+        this.rotations = rotations;
+    }
+
+    public intoRaw(): RawShape {
+        if (
+            this.shapes.length != this.translations.length ||
+            this.shapes.length != this.rotations.length
+        )
+            throw TypeError(
+                "The shapes, translations, and rotations of a compound must have the same length.",
+            );
+
+        let builder = new RawCompoundShapeBuilder();
+
+        for (let i = 0; i < this.shapes.length; ++i) {
+            let rawShape = this.shapes[i].intoRaw();
+            let rawTra = VectorOps.intoRaw(this.translations[i]);
+            let rawRot = RotationOps.intoRaw(this.rotations[i]);
+            builder.push(rawShape, rawTra, rawRot);
+            rawShape.free();
+            rawTra.free();
+            rawRot.free();
+        }
+
+        let result = builder.build();
+        builder.free();
+
+        if (!result)
+            throw TypeError(
+                "A compound shape must have at least one part, and its parts must not be composite shapes.",
+            );
+
+        return result;
     }
 }
 
@@ -936,20 +1290,37 @@ export class TriMesh extends Shape {
      */
     indices: Uint32Array;
 
+    /**
+     * The pre-processing applied to the triangle mesh when it is built.
+     */
+    flags: TriMeshFlags;
+
     /**
      * Creates a new triangle mesh shape.
      *
      * @param vertices - The coordinates of the triangle mesh's vertices.
      * @param indices - The indices of the triangle mesh's triangles.
+     * @param flags - (optional) The pre-processing applied to the triangle mesh. Defaults to
+     *   `TriMeshFlags.MERGE_DUPLICATE_VERTICES`.
      */
-    constructor(vertices: Float32Array, indices: Uint32Array) {
+    constructor(
+        vertices: Float32Array,
+        indices: Uint32Array,
+        flags?: TriMeshFlags,
+    ) {
         super();
         this.vertices = vertices;
         this.indices = indices;
+        this.flags =
+            flags === undefined ? TriMeshFlags.MERGE_DUPLICATE_VERTICES : flags;
     }
 
     public intoRaw(): RawShape {
-        return RawShape.trimesh(this.vertices, this.indices);
+        return RawShape.trimeshWithFlags(
+            this.vertices,
+            this.indices,
+            this.flags,
+        );
     }
 }
 
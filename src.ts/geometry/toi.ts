@@ -1,6 +1,6 @@
 import {Collider} from "./collider";
 import {Vector, VectorOps} from "../math";
-import {RawShapeTOI, RawShapeColliderTOI} from "../raw";
+import {RawShapeTOI, RawShapeColliderTOI, RawShapeCastHit} from "../raw";
 import {ColliderSet} from "./collider_set";
 
 /**
@@ -103,3 +103,71 @@ export class ShapeColliderTOI extends ShapeTOI {
         return result;
     }
 }
+
+/**
+ * The first collider hit by a shape-cast, with world-space contact information.
+ */
+export class ShapeCastHit {
+    /**
+     * The collider hit by the cast shape.
+     */
+    collider: Collider;
+    /**
+     * The time of impact of the two shapes.
+     */
+    toi: number;
+    /**
+     * The world-space contact point on the hit collider, at
+     * the time of impact.
+     */
+    witness1: Vector;
+    /**
+     * The world-space contact point on the cast shape, at
+     * the time of impact.
+     */
+    witness2: Vector;
+    /**
+     * The world-space normal on the hit collider, at
+     * the time of impact.
+     */
+    normal1: Vector;
+    /**
+     * The world-space normal on the cast shape, at
+     * the time of impact.
+     */
+    normal2: Vector;
+
+    constructor(
+        collider: Collider,
+        toi: number,
+        witness1: Vector,
+        witness2: Vector,
+        normal1: Vector,
+        normal2: Vector,
+    ) {
+        this.collider = collider;
+        this.toi = toi;
+        this.witness1 = witness1;
+        this.witness2 = witness2;
+        this.normal1 = normal1;
+        this.normal2 = normal2;
+    }
+
+    public static fromRaw(
+        colliderSet: ColliderSet,
+        raw: RawShapeCastHit,
+    ): ShapeCastHit {
+        if (!raw) return null;
+
+        const result = new ShapeCastHit(
+            colliderSet.get(raw.colliderHandle()),
+            raw.toi(),
+            VectorOps.fromRaw(raw.witness1()),
+            VectorOps.fromRaw(raw.witness2()),
+            VectorOps.fromRaw(raw.normal1()),
+            VectorOps.fromRaw(raw.normal2()),
+        );
+        raw.free();
+        return result;
+    }
+}
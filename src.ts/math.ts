@@ -167,6 +167,39 @@ export class RotationOps {
         return new RawRotation(rot.x, rot.y, rot.z, rot.w);
     }
 
+    /**
+     * Creates a rotation from Euler angles, in radians.
+     *
+     * The rotations are applied in the order roll, pitch, yaw, i.e., the result is
+     * `Rz(yaw) * Ry(pitch) * Rx(roll)`.
+     *
+     * @param roll - The rotation angle around the `x` axis.
+     * @param pitch - The rotation angle around the `y` axis.
+     * @param yaw - The rotation angle around the `z` axis.
+     */
+    public static fromEuler(
+        roll: number,
+        pitch: number,
+        yaw: number,
+    ): Rotation {
+        return RotationOps.fromRaw(RawRotation.fromEuler(roll, pitch, yaw));
+    }
+
+    /**
+     * The Euler angles of a rotation, in radians.
+     *
+     * Returns a vector with the roll, pitch, and yaw angles as its `x`, `y`, and `z`
+     * components, using the same convention as `RotationOps.fromEuler`.
+     *
+     * @param rot - The rotation to convert.
+     */
+    public static toEuler(rot: Rotation): Vector {
+        let rawRot = RotationOps.intoRaw(rot);
+        let result = VectorOps.fromRaw(rawRot.toEuler());
+        rawRot.free();
+        return result;
+    }
+
     public static copy(out: Rotation, input: Rotation) {
         out.x = input.x;
         out.y = input.y;
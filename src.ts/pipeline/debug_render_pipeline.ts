@@ -9,6 +9,45 @@ import {
 } from "../dynamics";
 import {BroadPhase, ColliderSet, NarrowPhase} from "../geometry";
 
+// NOTE: must match the bits in the DebugRenderMode on the Rust side.
+/**
+ * Flags selecting the elements rendered by the debug-render pipeline.
+ */
+export enum DebugRenderMode {
+    /**
+     * Render the shapes of the colliders.
+     */
+    COLLIDER_SHAPES = 0b0000_0001,
+    /**
+     * Render the local axes of the rigid-bodies.
+     */
+    RIGID_BODY_AXES = 0b0000_0010,
+    /**
+     * Render the multibody joints.
+     */
+    MULTIBODY_JOINTS = 0b0000_0100,
+    /**
+     * Render the impulse joints.
+     */
+    IMPULSE_JOINTS = 0b0000_1000,
+    /**
+     * Render all the joints.
+     */
+    JOINTS = DebugRenderMode.MULTIBODY_JOINTS | DebugRenderMode.IMPULSE_JOINTS,
+    /**
+     * Render the solver contacts.
+     */
+    SOLVER_CONTACTS = 0b0001_0000,
+    /**
+     * Render the contact points computed by the narrow-phase.
+     */
+    CONTACTS = 0b0010_0000,
+    /**
+     * Render the AABBs of the colliders.
+     */
+    COLLIDER_AABBS = 0b0100_0000,
+}
+
 /**
  * The vertex and color buffers for debug-redering the physics scene.
  */
@@ -60,6 +99,19 @@ export class DebugRenderPipeline {
         this.raw = raw || new RawDebugRenderPipeline();
     }
 
+    /**
+     * The elements rendered by this pipeline (default: `COLLIDER_SHAPES | JOINTS | RIGID_BODY_AXES`).
+     *
+     * Bits that don't match any `DebugRenderMode` flag are ignored.
+     */
+    get mode(): DebugRenderMode {
+        return this.raw.mode;
+    }
+
+    set mode(mode: DebugRenderMode) {
+        this.raw.mode = mode;
+    }
+
     public render(
         bodies: RigidBodySet,
         colliders: ColliderSet,
@@ -1,10 +1,14 @@
-import {RawContactForceEvent, RawEventQueue} from "../raw";
+import {RawContactForceEvent, RawEventQueue, RawVector} from "../raw";
 import {RigidBodyHandle} from "../dynamics";
 import {Collider, ColliderHandle} from "../geometry";
 import {Vector, VectorOps} from "../math";
 
+// NOTE: must match the bits in the ActiveEvents on the Rust side.
 /**
  * Flags indicating what events are enabled for colliders.
+ *
+ * Events are only generated for pairs where at least one collider enabled them, so
+ * enabling them only on the colliders of interest keeps the event queue small.
  */
 export enum ActiveEvents {
     /**
@@ -113,6 +117,27 @@ export class EventQueue {
         this.raw = undefined;
     }
 
+    /**
+     * Sets whether collision events between two non-sensor colliders are discarded.
+     *
+     * When enabled, only the collision events involving at least one sensor collider are
+     * collected, so solid contacts don't have to be transferred to JavaScript when only
+     * sensor triggers matter. Contact force events are unaffected and can be drained
+     * independently with `this.drainContactForceEvents`.
+     *
+     * @param enabled - If `true`, only sensor events are collected.
+     */
+    public setSensorEventsOnly(enabled: boolean) {
+        this.raw.setSensorEventsOnly(enabled);
+    }
+
+    /**
+     * Are collision events between two non-sensor colliders discarded?
+     */
+    public sensorEventsOnly(): boolean {
+        return this.raw.sensorEventsOnly();
+    }
+
     /**
      * Applies the given javascript closure on each collision event of this collector, then clear
      * the internal collision event buffer.
@@ -132,6 +157,44 @@ export class EventQueue {
         this.raw.drainCollisionEvents(f);
     }
 
+    /**
+     * Applies the given javascript closure on each collision event of this collector, together with
+     * the contact data of the collisions that started, then clear the internal collision event buffer.
+     *
+     * @param f - JavaScript closure applied to each collision event. Its first three arguments are the
+     * same as for `this.drainCollisionEvents`. If the collision started and involves a contact (not a
+     * sensor intersection), `normal` is the world-space contact normal pointing from the first collider
+     * toward the second, and `point` is the world-space position of the deepest contact point on the
+     * first collider. Otherwise, both are `null`.
+     */
+    public drainCollisionEventsWithContacts(
+        f: (
+            handle1: ColliderHandle,
+            handle2: ColliderHandle,
+            started: boolean,
+            normal: Vector | null,
+            point: Vector | null,
+        ) => void,
+    ) {
+        this.raw.drainCollisionEventsWithContacts(
+            (
+                handle1: ColliderHandle,
+                handle2: ColliderHandle,
+                started: boolean,
+                rawNormal: RawVector | null,
+                rawPoint: RawVector | null,
+            ) => {
+                f(
+                    handle1,
+                    handle2,
+                    started,
+                    VectorOps.fromRaw(rawNormal),
+                    VectorOps.fromRaw(rawPoint),
+                );
+            },
+        );
+    }
+
     /**
      * Applies the given javascript closure on each contact force event of this collector, then clear
      * the internal collision event buffer.
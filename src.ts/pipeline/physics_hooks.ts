@@ -1,10 +1,14 @@
 import {RigidBodyHandle} from "../dynamics";
 import {ColliderHandle} from "../geometry";
 
+// NOTE: must match the bits in the ActiveHooks on the Rust side.
+/**
+ * Flags indicating what physics hooks are enabled for colliders.
+ */
 export enum ActiveHooks {
     FILTER_CONTACT_PAIRS = 0b0001,
     FILTER_INTERSECTION_PAIRS = 0b0010,
-    // MODIFY_SOLVER_CONTACTS = 0b0100, /* Not supported yet in JS. */
+    MODIFY_SOLVER_CONTACTS = 0b0100,
 }
 
 export enum SolverFlags {
@@ -12,13 +16,20 @@ export enum SolverFlags {
     COMPUTE_IMPULSE = 0b001,
 }
 
+/**
+ * User-defined functions called during `World.step` to filter contact and intersection pairs.
+ *
+ * Each call crosses the WASM/JavaScript boundary, so these functions are only called for
+ * pairs involving at least one collider with the corresponding `ActiveHooks` flag. Enable
+ * them only on the colliders that need them, e.g., one-way platforms.
+ */
 export interface PhysicsHooks {
     /**
      * Function that determines if contacts computation should happen between two colliders, and how the
      * constraints solver should behave for these contacts.
      *
      * This will only be executed and taken into account if at least one of the involved colliders contains the
-     * `ActiveHooks.FILTER_CONTACT_PAIR` flag in its active hooks.
+     * `ActiveHooks.FILTER_CONTACT_PAIRS` flag in its active hooks.
      *
      * @param collider1 − Handle of the first collider involved in the potential contact.
      * @param collider2 − Handle of the second collider involved in the potential contact.
@@ -36,8 +47,8 @@ export interface PhysicsHooks {
      * Function that determines if intersection computation should happen between two colliders (where at least
      * one is a sensor).
      *
-     * This will only be executed and taken into account if `one of the involved colliders contains the
-     * `ActiveHooks.FILTER_INTERSECTION_PAIR` flag in its active hooks.
+     * This will only be executed and taken into account if one of the involved colliders contains the
+     * `ActiveHooks.FILTER_INTERSECTION_PAIRS` flag in its active hooks.
      *
      * @param collider1 − Handle of the first collider involved in the potential contact.
      * @param collider2 − Handle of the second collider involved in the potential contact.
@@ -50,4 +61,31 @@ export interface PhysicsHooks {
         body1: RigidBodyHandle,
         body2: RigidBodyHandle,
     ): boolean;
+
+    /**
+     * Function that modifies the contacts given to the constraints solver for a pair of colliders.
+     *
+     * This will only be executed if one of the involved colliders contains the
+     * `ActiveHooks.MODIFY_SOLVER_CONTACTS` flag in its active hooks.
+     *
+     * The `contacts` array contains `2 + d` numbers per solver contact, where `d` is the dimension
+     * (2 or 3): its friction coefficient, its restitution coefficient, then its tangent velocity.
+     * Modifications made to this array are applied to the solver contacts once this function
+     * returns. Setting a non-zero tangent velocity makes the contact surface act like a
+     * conveyor belt. If this function throws, the exception is silently ignored and the solver
+     * contacts are left unchanged.
+     *
+     * @param collider1 − Handle of the first collider involved in the contact.
+     * @param collider2 − Handle of the second collider involved in the contact.
+     * @param body1 − Handle of the first body involved in the contact.
+     * @param body2 − Handle of the second body involved in the contact.
+     * @param contacts − The friction, restitution, and tangent velocity of each solver contact.
+     */
+    modifySolverContacts?(
+        collider1: ColliderHandle,
+        collider2: ColliderHandle,
+        body1: RigidBodyHandle,
+        body2: RigidBodyHandle,
+        contacts: Float32Array,
+    ): void;
 }
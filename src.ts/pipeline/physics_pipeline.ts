@@ -18,6 +18,13 @@ import {
 import {EventQueue} from "./event_queue";
 import {PhysicsHooks} from "./physics_hooks";
 
+/**
+ * The physics pipeline, responsible for advancing the simulation.
+ *
+ * The simulation always runs serially on the calling thread: it doesn't require WASM
+ * threads, `SharedArrayBuffer`, or cross-origin isolation, so it works on every browser
+ * supporting WebAssembly. There is no solver thread count to configure.
+ */
 export class PhysicsPipeline {
     raw: RawPhysicsPipeline;
 
@@ -32,6 +39,15 @@ export class PhysicsPipeline {
         this.raw = raw || new RawPhysicsPipeline();
     }
 
+    /**
+     * Advances the simulation by `integrationParameters.dt`.
+     *
+     * @param substeps - (optional) if greater than one, the timestep is split into `substeps`
+     *   steps of equal length `dt / substeps`, all performed within this single call. The whole
+     *   `dt` is always consumed: no remainder time is accumulated from one call to the next.
+     *   Must be a positive integer. Position-based kinematic rigid-bodies are moved by a fraction
+     *   `1 / substeps` of their motion at each substep.
+     */
     public step(
         gravity: Vector,
         integrationParameters: IntegrationParameters,
@@ -45,10 +61,56 @@ export class PhysicsPipeline {
         ccdSolver: CCDSolver,
         eventQueue?: EventQueue,
         hooks?: PhysicsHooks,
+        substeps?: number,
     ) {
+        if (
+            substeps !== undefined &&
+            !(
+                Number.isInteger(substeps) &&
+                substeps >= 1 &&
+                substeps <= 0xffffffff
+            )
+        )
+            throw RangeError("The number of substeps must be a positive integer.");
+
         let rawG = VectorOps.intoRaw(gravity);
 
-        if (!!eventQueue) {
+        if (substeps !== undefined && substeps > 1) {
+            if (!!eventQueue) {
+                this.raw.stepSubsteppedWithEvents(
+                    rawG,
+                    integrationParameters.raw,
+                    islands.raw,
+                    broadPhase.raw,
+                    narrowPhase.raw,
+                    bodies.raw,
+                    colliders.raw,
+                    impulseJoints.raw,
+                    multibodyJoints.raw,
+                    ccdSolver.raw,
+                    eventQueue.raw,
+                    hooks,
+                    !!hooks ? hooks.filterContactPair : null,
+                    !!hooks ? hooks.filterIntersectionPair : null,
+                    !!hooks ? hooks.modifySolverContacts : null,
+                    substeps,
+                );
+            } else {
+                this.raw.stepSubstepped(
+                    rawG,
+                    integrationParameters.raw,
+                    islands.raw,
+                    broadPhase.raw,
+                    narrowPhase.raw,
+                    bodies.raw,
+                    colliders.raw,
+                    impulseJoints.raw,
+                    multibodyJoints.raw,
+                    ccdSolver.raw,
+                    substeps,
+                );
+            }
+        } else if (!!eventQueue) {
             this.raw.stepWithEvents(
                 rawG,
                 integrationParameters.raw,
@@ -64,6 +126,7 @@ export class PhysicsPipeline {
                 hooks,
                 !!hooks ? hooks.filterContactPair : null,
                 !!hooks ? hooks.filterIntersectionPair : null,
+                !!hooks ? hooks.modifySolverContacts : null,
             );
         } else {
             this.raw.step(
@@ -8,6 +8,7 @@ import {
     RayColliderIntersection,
     RayColliderToi,
     Shape,
+    ShapeCastHit,
     ShapeColliderTOI,
 } from "../geometry";
 import {IslandManager, RigidBodyHandle, RigidBodySet} from "../dynamics";
@@ -23,11 +24,11 @@ export enum QueryFilterFlags {
      */
     EXCLUDE_FIXED = 0b0000_0001,
     /**
-     * Exclude from the query any collider attached to a dynamic rigid-body.
+     * Exclude from the query any collider attached to a kinematic rigid-body.
      */
     EXCLUDE_KINEMATIC = 0b0000_0010,
     /**
-     * Exclude from the query any collider attached to a kinematic rigid-body.
+     * Exclude from the query any collider attached to a dynamic rigid-body.
      */
     EXCLUDE_DYNAMIC = 0b0000_0100,
     /**
@@ -81,6 +82,11 @@ export class QueryPipeline {
 
     /**
      * Updates the acceleration structure of the query pipeline.
+     *
+     * Queries made before this update see the collider positions of the previous update. The
+     * positions of colliders attached to rigid-bodies moved manually must be propagated first,
+     * see `World.updateSceneQueries` which does both.
+     *
      * @param bodies - The set of rigid-bodies taking part in this pipeline.
      * @param colliders - The set of colliders taking part in this pipeline.
      */
@@ -99,7 +105,8 @@ export class QueryPipeline {
      *   origin already lies inside of a shape. In other terms, `true` implies that all shapes are plain,
      *   whereas `false` implies that all shapes are hollow for this ray-cast.
      * @param groups - Used to filter the colliders that can or cannot be hit by the ray.
-     * @param filter - The callback to filter out which collider will be hit.
+     * @param filterPredicate - The callback to filter out which collider will be hit. It is called
+     *   once per candidate collider, so keep it cheap: each call crosses the WASM/JS boundary.
      */
     public castRay(
         bodies: RigidBodySet,
@@ -138,6 +145,52 @@ export class QueryPipeline {
         return result;
     }
 
+    /**
+     * Find the closest intersection between each ray of a batch and a set of collider.
+     *
+     * All the rays are cast with a single WASM call, which is much faster than calling
+     * `castRay` for each of them when there are many rays.
+     *
+     * @param colliders - The set of colliders taking part in this pipeline.
+     * @param rayOrigins - The origins of the rays, as a flat array of 2 (in 2D) or 3 (in 3D)
+     *   components per ray.
+     * @param rayDirs - The directions of the rays, with the same layout as `rayOrigins`.
+     * @param maxToi - The maximum time-of-impact that can be reported by these casts.
+     * @param solid - If `false` then the rays will attempt to hit the boundary of a shape, even if their
+     *   origin already lies inside of a shape.
+     * @param groups - Used to filter the colliders that can or cannot be hit by the rays.
+     * @param filterPredicate - The callback to filter out which collider will be hit.
+     * @returns Two numbers per ray: its time-of-impact followed by the handle of the collider it
+     *   hit. Both are `NaN` if the ray didn't hit anything.
+     */
+    public castRays(
+        bodies: RigidBodySet,
+        colliders: ColliderSet,
+        rayOrigins: Float32Array,
+        rayDirs: Float32Array,
+        maxToi: number,
+        solid: boolean,
+        filterFlags?: QueryFilterFlags,
+        filterGroups?: InteractionGroups,
+        filterExcludeCollider?: ColliderHandle,
+        filterExcludeRigidBody?: RigidBodyHandle,
+        filterPredicate?: (collider: ColliderHandle) => boolean,
+    ): Float64Array {
+        return this.raw.castRays(
+            bodies.raw,
+            colliders.raw,
+            rayOrigins,
+            rayDirs,
+            maxToi,
+            solid,
+            filterFlags,
+            filterGroups,
+            filterExcludeCollider,
+            filterExcludeRigidBody,
+            filterPredicate,
+        );
+    }
+
     /**
      * Find the closest intersection between a ray and a set of collider.
      *
@@ -473,6 +526,73 @@ export class QueryPipeline {
         return result;
     }
 
+    /**
+     * Casts a shape at a constant linear velocity and retrieve the first collider it hits, with
+     * the contact points and normals expressed in world-space.
+     *
+     * This is the same as `this.castShape`, except that the contact information is given in
+     * world-space, at the time of impact.
+     *
+     * @param colliders - The set of colliders taking part in this pipeline.
+     * @param shapePos - The initial position of the shape to cast.
+     * @param shapeRot - The initial rotation of the shape to cast.
+     * @param shapeVel - The constant velocity of the shape to cast (i.e. the cast direction).
+     * @param shape - The shape to cast.
+     * @param maxToi - The maximum time-of-impact that can be reported by this cast. This effectively
+     *   limits the distance traveled by the shape to `shapeVel.norm() * maxToi`.
+     * @param stopAtPenetration - If set to `false`, the linear shape-cast won’t immediately stop if
+     *   the shape is penetrating another shape at its starting point **and** its trajectory is such
+     *   that it’s on a path to exit that penetration state.
+     * @param groups - The bit groups and filter associated to the shape to cast, in order to only
+     *   test on colliders with collision groups compatible with this group.
+     */
+    public castShapeWithWitness(
+        bodies: RigidBodySet,
+        colliders: ColliderSet,
+        shapePos: Vector,
+        shapeRot: Rotation,
+        shapeVel: Vector,
+        shape: Shape,
+        maxToi: number,
+        stopAtPenetration: boolean,
+        filterFlags?: QueryFilterFlags,
+        filterGroups?: InteractionGroups,
+        filterExcludeCollider?: ColliderHandle,
+        filterExcludeRigidBody?: RigidBodyHandle,
+        filterPredicate?: (collider: ColliderHandle) => boolean,
+    ): ShapeCastHit | null {
+        let rawPos = VectorOps.intoRaw(shapePos);
+        let rawRot = RotationOps.intoRaw(shapeRot);
+        let rawVel = VectorOps.intoRaw(shapeVel);
+        let rawShape = shape.intoRaw();
+
+        let result = ShapeCastHit.fromRaw(
+            colliders,
+            this.raw.castShapeWithWitness(
+                bodies.raw,
+                colliders.raw,
+                rawPos,
+                rawRot,
+                rawVel,
+                rawShape,
+                maxToi,
+                stopAtPenetration,
+                filterFlags,
+                filterGroups,
+                filterExcludeCollider,
+                filterExcludeRigidBody,
+                filterPredicate,
+            ),
+        );
+
+        rawPos.free();
+        rawRot.free();
+        rawVel.free();
+        rawShape.free();
+
+        return result;
+    }
+
     /**
      * Retrieve all the colliders intersecting the given shape.
      *
@@ -526,7 +646,7 @@ export class QueryPipeline {
      * @param aabbCenter - The center of the AABB to test.
      * @param aabbHalfExtents - The half-extents of the AABB to test.
      * @param callback - The callback that will be called with the handles of all the colliders
-     *                   currently intersecting the given AABB.
+     *                   currently intersecting the given AABB. Return `false` to stop the search.
      */
     public collidersWithAabbIntersectingAabb(
         aabbCenter: Vector,
@@ -13,6 +13,7 @@ import {
     RawRigidBodySet,
     RawSerializationPipeline,
     RawDebugRenderPipeline,
+    RawVector,
 } from "../raw";
 
 import {
@@ -28,6 +29,7 @@ import {
     RayColliderIntersection,
     RayColliderToi,
     Shape,
+    ShapeCastHit,
     ShapeColliderTOI,
     TempContactManifold,
 } from "../geometry";
@@ -61,13 +63,38 @@ import {Coarena} from "../coarena";
 import {DynamicRayCastVehicleController} from "../control";
 // #endif
 
+/**
+ * The handle remap tables returned by `World.mergeFrom`.
+ *
+ * Each map associates the handle of an object of the source world to the handle of its copy.
+ */
+export interface WorldMergeRemap {
+    bodies: Map<RigidBodyHandle, RigidBodyHandle>;
+    colliders: Map<ColliderHandle, ColliderHandle>;
+    impulseJoints: Map<ImpulseJointHandle, ImpulseJointHandle>;
+    multibodyJoints: Map<MultibodyJointHandle, MultibodyJointHandle>;
+}
+
 /**
  * The physics world.
  *
  * This contains all the data-structures necessary for creating and simulating
  * bodies with contacts, joints, and external forces.
+ *
+ * The simulation is cross-platform deterministic: two worlds created, modified, and stepped
+ * with the same operations in the same order produce bit-identical results on any machine.
+ * This assumes that the JavaScript inputs are themselves deterministic (including the order
+ * in which bodies, colliders, and joints are inserted and removed, and the results of physics
+ * hooks and query filters). A world restored from a snapshot continues deterministically.
  */
 export class World {
+    /**
+     * The gravity applied to every dynamic rigid-body of this world.
+     *
+     * It is read at each call to `this.step`, so it can be modified between two steps, e.g., to flip
+     * the gravity direction. Each rigid-body scales it by its own gravity scale, see
+     * `RigidBody.setGravityScale`.
+     */
     public gravity: Vector;
     integrationParameters: IntegrationParameters;
     islands: IslandManager;
@@ -88,6 +115,8 @@ export class World {
     vehicleControllers: Set<DynamicRayCastVehicleController>;
     // #endif
 
+    private worldAnchor: RigidBody | null = null;
+
     /**
      * Release the WASM memory occupied by this physics world.
      *
@@ -134,6 +163,84 @@ export class World {
         // #endif
     }
 
+    /**
+     * Removes every rigid-body, collider, and joint from this physics world.
+     *
+     * The body, collider, and joint sets are cleared together with the island manager, the
+     * broad-phase, the narrow-phase, the CCD solver, and the query pipeline, so no stale pair or
+     * proxy survives, e.g., when reloading a level. The gravity, integration parameters, and
+     * character controllers are kept, while vehicle controllers are freed since their chassis
+     * is removed. All the handles and objects previously obtained from this world become
+     * invalid, and their handle values may be reused by new objects.
+     *
+     * The memory allocated for these structures is not kept for the objects added afterward:
+     * Rapier doesn't expose a way to empty them in place, so each one is replaced by a new,
+     * empty one. Removing the objects one by one instead would keep their capacity, but would
+     * leave the pairs and proxies of the removed colliders around until the next step.
+     */
+    public clear() {
+        this.bodies.clear();
+        this.colliders.clear();
+        this.impulseJoints.clear();
+        this.multibodyJoints.clear();
+        this.islands.raw.clear();
+        this.broadPhase.raw.clear();
+        this.narrowPhase.raw.clear();
+        this.ccdSolver.raw.clear();
+        this.queryPipeline.raw.clear();
+
+        // #if DIM3
+        this.vehicleControllers.forEach((controller) => controller.free());
+        this.vehicleControllers.clear();
+        // #endif
+
+        this.worldAnchor = null;
+    }
+
+    /**
+     * Inserts a copy of every rigid-body, collider, and joint of another world into this world.
+     *
+     * This is useful for streaming levels by chunks: each chunk can be built in its own world,
+     * then stitched into the simulated world without rebuilding it. The other world is left
+     * unchanged, and its gravity, integration parameters, and controllers are ignored. Contacts
+     * between the copies are recomputed at the next step.
+     *
+     * @param other - The world to copy the objects of.
+     * @returns The maps from the handles of the objects of `other` to the handles of their copies,
+     *   which can be used to update the references kept by the application.
+     */
+    public mergeFrom(other: World): WorldMergeRemap {
+        let bodies = this.bodies.mergeFrom(this.colliders, other.bodies);
+        let colliders = this.colliders.mergeFrom(
+            this.bodies,
+            other.colliders,
+            bodies,
+        );
+        let impulseJoints = this.impulseJoints.mergeFrom(
+            this.bodies,
+            other.impulseJoints,
+            bodies,
+        );
+        let multibodyJoints = this.multibodyJoints.mergeFrom(
+            other.multibodyJoints,
+            bodies,
+        );
+        this.queryPipeline.update(this.bodies, this.colliders);
+
+        // Adopt the copy of the world anchor of `other` if this world doesn't have one yet.
+        // Otherwise, the copy is unmarked so it can't be mistaken for the anchor of this world.
+        if (!!other.worldAnchor && other.worldAnchor.isValid()) {
+            let copy = this.bodies.get(bodies.get(other.worldAnchor.handle));
+            if (!!this.worldAnchor && this.worldAnchor.isValid()) {
+                this.bodies.raw.rbSetUserData(copy.handle, 0);
+            } else {
+                this.worldAnchor = copy;
+            }
+        }
+
+        return {bodies, colliders, impulseJoints, multibodyJoints};
+    }
+
     constructor(
         gravity: Vector,
         rawIntegrationParameters?: RawIntegrationParameters,
@@ -179,12 +286,16 @@ export class World {
         this.impulseJoints.finalizeDeserialization(this.bodies);
         this.bodies.finalizeDeserialization(this.colliders);
         this.colliders.finalizeDeserialization(this.bodies);
+
+        // Find the world anchor again if these sets come from a snapshot.
+        let anchor = this.bodies.raw.worldAnchor();
+        if (anchor !== undefined) this.worldAnchor = this.bodies.get(anchor);
     }
 
     public static fromRaw(raw: RawDeserializedWorld): World {
         if (!raw) return null;
 
-        return new World(
+        let world = new World(
             VectorOps.fromRaw(raw.takeGravity()),
             raw.takeIntegrationParameters(),
             raw.takeIslandManager(),
@@ -195,6 +306,10 @@ export class World {
             raw.takeImpulseJoints(),
             raw.takeMultibodyJoints(),
         );
+        // The query pipeline isn't part of the snapshot: rebuild it so scene
+        // queries work right away, without waiting for the next step.
+        world.queryPipeline.update(world.bodies, world.colliders);
+        return world;
     }
 
     /**
@@ -221,6 +336,8 @@ export class World {
      * Creates a new physics world from a snapshot.
      *
      * This new physics world will be an identical copy of the snapshoted physics world.
+     * All the rigid-body, collider, and joint handles are preserved, so handles obtained
+     * before the snapshot was taken remain valid for the restored world.
      */
     public static restoreSnapshot(data: Uint8Array): World {
         let deser = new SerializationPipeline();
@@ -249,10 +366,26 @@ export class World {
      *
      * All events generated by the physics engine are ignored.
      *
+     * A step runs, in order: the broad-phase, the narrow-phase (calling the contact and intersection
+     * filtering hooks), the constraints solver (preceded by the `modifySolverContacts` hook), the
+     * position integration, and CCD. The world can't be accessed from JavaScript while the step is
+     * running, so there is no general pre-solve or post-integration callback: forces depending on the
+     * contacts (e.g. buoyancy) should be applied before calling `step`, using the contacts computed by
+     * the previous step, and post-integration logic should run after `step` returns.
+     *
      * @param EventQueue - (optional) structure responsible for collecting
      *   events generated by the physics engine.
+     * @param hooks - (optional) user-defined hooks called during the narrow-phase and before the
+     *   constraints solver.
+     * @param substeps - (optional) number of substeps of length `timestep / substeps` performed
+     *   during this step. No remainder time is carried over from one step to the next. Must be a
+     *   positive integer.
      */
-    public step(eventQueue?: EventQueue, hooks?: PhysicsHooks) {
+    public step(
+        eventQueue?: EventQueue,
+        hooks?: PhysicsHooks,
+        substeps?: number,
+    ) {
         this.physicsPipeline.step(
             this.gravity,
             this.integrationParameters,
@@ -266,6 +399,7 @@ export class World {
             this.ccdSolver,
             eventQueue,
             hooks,
+            substeps,
         );
         this.queryPipeline.update(this.bodies, this.colliders);
     }
@@ -286,7 +420,14 @@ export class World {
     /**
      * Ensure subsequent scene queries take into account the collider positions set before this method is called.
      *
-     * This does not step the physics simulation forward.
+     * This does not step the physics simulation forward. Scene queries are only refreshed
+     * automatically at the end of `World.step`, so queries made after moving rigid-bodies or
+     * colliders (e.g., with `RigidBody.setTranslation`) see their stale positions until either
+     * this method is called or the world is stepped.
+     *
+     * Note that `RigidBody.setNextKinematicTranslation` and `setNextKinematicRotation` only move
+     * a kinematic rigid-body during the next step: use `RigidBody.setTranslation` before calling
+     * this method to make a kinematic rigid-body visible to queries at its new position right away.
      */
     public updateSceneQueries() {
         this.propagateModifiedBodyPositionsToColliders();
@@ -454,20 +595,51 @@ export class World {
         return this.colliders.createCollider(this.bodies, desc, parentHandle);
     }
 
+    /**
+     * The fixed rigid-body, at the origin of the world, used to anchor impulse joints to the world.
+     *
+     * It is created the first time it is needed, e.g., by `this.createImpulseJoint` with a `null`
+     * second rigid-body, and is then shared by all the joints anchored to the world. It is a
+     * regular rigid-body: it is part of `this.bodies` and of the snapshots of this world, and a
+     * world restored from a snapshot keeps using the same one. It is created again if it was
+     * removed.
+     */
+    public worldAnchorBody(): RigidBody {
+        if (!this.worldAnchor || !this.worldAnchor.isValid()) {
+            this.worldAnchor = this.createRigidBody(RigidBodyDesc.fixed());
+            this.bodies.raw.markWorldAnchor(this.worldAnchor.handle);
+        }
+
+        return this.worldAnchor;
+    }
+
     /**
      * Creates a new impulse joint from the given joint descriptor.
      *
+     * Impulse joints are solved as constraints between independent rigid-bodies. They can form
+     * any graph, including loops, but long chains of bodies may stretch under heavy loads. See
+     * `this.createMultibodyJoint` for a more stable alternative.
+     *
+     * To anchor a rigid-body to a fixed point of the world, e.g., for a pendulum, pass `null` as
+     * `parent2`: the joint is then attached to `this.worldAnchorBody()`, so the second anchor of
+     * the joint is expressed in world-space.
+     *
      * @param params - The description of the joint to create.
      * @param parent1 - The first rigid-body attached to this joint.
-     * @param parent2 - The second rigid-body attached to this joint.
+     * @param parent2 - The second rigid-body attached to this joint, or `null` to attach the
+     *   first rigid-body to the world.
      * @param wakeUp - Should the attached rigid-bodies be awakened?
      */
     public createImpulseJoint(
         params: JointData,
         parent1: RigidBody,
-        parent2: RigidBody,
+        parent2: RigidBody | null,
         wakeUp: boolean,
     ): ImpulseJoint {
+        if (!parent2) {
+            parent2 = this.worldAnchorBody();
+        }
+
         return this.impulseJoints.createJoint(
             this.bodies,
             params,
@@ -480,17 +652,24 @@ export class World {
     /**
      * Creates a new multibody joint from the given joint descriptor.
      *
+     * Multibody joints use a reduced-coordinates formulation: the rigid-bodies they link form a
+     * tree whose joints can't drift apart, which makes them much more stable than impulse joints
+     * for long chains like robotic arms or ragdolls. All the joint types (fixed, revolute,
+     * prismatic, spherical in 3D, and generic joints) are supported. However, multibody joints
+     * can't form loops, and each rigid-body can have at most one parent multibody joint.
+     *
      * @param params - The description of the joint to create.
-     * @param parent1 - The first rigid-body attached to this joint.
-     * @param parent2 - The second rigid-body attached to this joint.
+     * @param parent1 - The first rigid-body attached to this joint, i.e., the parent link.
+     * @param parent2 - The second rigid-body attached to this joint, i.e., the child link.
      * @param wakeUp - Should the attached rigid-bodies be awakened?
+     * @returns `null` if the joint can't be added, e.g., because it would create a loop.
      */
     public createMultibodyJoint(
         params: JointData,
         parent1: RigidBody,
         parent2: RigidBody,
         wakeUp: boolean,
-    ): MultibodyJoint {
+    ): MultibodyJoint | null {
         return this.multibodyJoints.createJoint(
             params,
             parent1.handle,
@@ -637,7 +816,8 @@ export class World {
      *   origin already lies inside of a shape. In other terms, `true` implies that all shapes are plain,
      *   whereas `false` implies that all shapes are hollow for this ray-cast.
      * @param groups - Used to filter the colliders that can or cannot be hit by the ray.
-     * @param filter - The callback to filter out which collider will be hit.
+     * @param filterPredicate - The callback to filter out which collider will be hit. It is called
+     *   once per candidate collider, so keep it cheap: each call crosses the WASM/JS boundary.
      */
     public castRay(
         ray: Ray,
@@ -663,6 +843,48 @@ export class World {
         );
     }
 
+    /**
+     * Find the closest intersection between each ray of a batch and the physics world.
+     *
+     * All the rays are cast with a single WASM call, which is much faster than calling
+     * `castRay` for each of them when there are many rays.
+     *
+     * @param rayOrigins - The origins of the rays, as a flat array of 2 (in 2D) or 3 (in 3D)
+     *   components per ray.
+     * @param rayDirs - The directions of the rays, with the same layout as `rayOrigins`.
+     * @param maxToi - The maximum time-of-impact that can be reported by these casts.
+     * @param solid - If `false` then the rays will attempt to hit the boundary of a shape, even if their
+     *   origin already lies inside of a shape.
+     * @param groups - Used to filter the colliders that can or cannot be hit by the rays.
+     * @returns Two numbers per ray: its time-of-impact followed by the handle of the collider it
+     *   hit (see `getCollider`). Both are `NaN` if the ray didn't hit anything.
+     */
+    public castRays(
+        rayOrigins: Float32Array,
+        rayDirs: Float32Array,
+        maxToi: number,
+        solid: boolean,
+        filterFlags?: QueryFilterFlags,
+        filterGroups?: InteractionGroups,
+        filterExcludeCollider?: Collider,
+        filterExcludeRigidBody?: RigidBody,
+        filterPredicate?: (collider: Collider) => boolean,
+    ): Float64Array {
+        return this.queryPipeline.castRays(
+            this.bodies,
+            this.colliders,
+            rayOrigins,
+            rayDirs,
+            maxToi,
+            solid,
+            filterFlags,
+            filterGroups,
+            filterExcludeCollider ? filterExcludeCollider.handle : null,
+            filterExcludeRigidBody ? filterExcludeRigidBody.handle : null,
+            this.colliders.castClosure(filterPredicate),
+        );
+    }
+
     /**
      * Find the closest intersection between a ray and the physics world.
      *
@@ -807,7 +1029,11 @@ export class World {
     }
 
     /**
-     * Find the projection of a point on the closest collider.
+     * Find the projection of a point on the closest collider, along with the geometric feature
+     * it was projected on.
+     *
+     * The point is projected on the boundary of the colliders. The `featureType` and `featureId`
+     * of the result identify the vertex, edge (in 3D), or face the point was projected on.
      *
      * @param point - The point to project.
      * @param groups - The bit groups and filter associated to the point to project, in order to only
@@ -911,9 +1137,61 @@ export class World {
         );
     }
 
+    /**
+     * Casts a shape at a constant linear velocity and retrieve the first collider it hits, with
+     * the contact points and normals expressed in world-space.
+     *
+     * This is the same as `this.castShape`, except that the contact information is given in
+     * world-space, at the time of impact.
+     *
+     * @param shapePos - The initial position of the shape to cast.
+     * @param shapeRot - The initial rotation of the shape to cast.
+     * @param shapeVel - The constant velocity of the shape to cast (i.e. the cast direction).
+     * @param shape - The shape to cast.
+     * @param maxToi - The maximum time-of-impact that can be reported by this cast. This effectively
+     *   limits the distance traveled by the shape to `shapeVel.norm() * maxToi`.
+     * @param stopAtPenetration - If set to `false`, the linear shape-cast won’t immediately stop if
+     *   the shape is penetrating another shape at its starting point **and** its trajectory is such
+     *   that it’s on a path to exit that penetration state.
+     * @param groups - The bit groups and filter associated to the shape to cast, in order to only
+     *   test on colliders with collision groups compatible with this group.
+     */
+    public castShapeWithWitness(
+        shapePos: Vector,
+        shapeRot: Rotation,
+        shapeVel: Vector,
+        shape: Shape,
+        maxToi: number,
+        stopAtPenetration: boolean,
+        filterFlags?: QueryFilterFlags,
+        filterGroups?: InteractionGroups,
+        filterExcludeCollider?: Collider,
+        filterExcludeRigidBody?: RigidBody,
+        filterPredicate?: (collider: Collider) => boolean,
+    ): ShapeCastHit | null {
+        return this.queryPipeline.castShapeWithWitness(
+            this.bodies,
+            this.colliders,
+            shapePos,
+            shapeRot,
+            shapeVel,
+            shape,
+            maxToi,
+            stopAtPenetration,
+            filterFlags,
+            filterGroups,
+            filterExcludeCollider ? filterExcludeCollider.handle : null,
+            filterExcludeRigidBody ? filterExcludeRigidBody.handle : null,
+            this.colliders.castClosure(filterPredicate),
+        );
+    }
+
     /**
      * Retrieve all the colliders intersecting the given shape.
      *
+     * Sensors and colliders attached to sleeping rigid-bodies are reported as well, unless
+     * they are excluded by the filter flags.
+     *
      * @param shapePos - The position of the shape to test.
      * @param shapeRot - The orientation of the shape to test.
      * @param shape - The shape to test.
@@ -948,17 +1226,23 @@ export class World {
     }
 
     /**
-     * Finds the handles of all the colliders with an AABB intersecting the given AABB.
+     * Finds all the colliders with an AABB intersecting the given AABB.
+     *
+     * Only the bounding boxes are compared, using the acceleration structure of the scene
+     * queries, which makes this much cheaper than an exact shape intersection test. This is
+     * suited for a coarse first pass, e.g., to find what is within a zone before running
+     * precise tests on the colliders found. An AABB given by its bounds `mins` and `maxs` has
+     * the center `(mins + maxs) / 2` and the half-extents `(maxs - mins) / 2`.
      *
      * @param aabbCenter - The center of the AABB to test.
      * @param aabbHalfExtents - The half-extents of the AABB to test.
-     * @param callback - The callback that will be called with the handles of all the colliders
-     *                   currently intersecting the given AABB.
+     * @param callback - The callback that will be called with all the colliders currently
+     *                   intersecting the given AABB. Return `false` to stop the search.
      */
     public collidersWithAabbIntersectingAabb(
         aabbCenter: Vector,
         aabbHalfExtents: Vector,
-        callback: (handle: Collider) => boolean,
+        callback: (collider: Collider) => boolean,
     ) {
         this.queryPipeline.collidersWithAabbIntersectingAabb(
             aabbCenter,
@@ -980,9 +1264,46 @@ export class World {
         );
     }
 
+    /**
+     * Enumerates all the colliders in contact with the colliders attached to the given rigid-body.
+     *
+     * This aggregates the contacts at the rigid-body level, e.g., to find out which side of a
+     * character is being pushed and how hard.
+     *
+     * @param body - The rigid-body whose contacts are enumerated.
+     * @param f - Closure called for each contact pair with an active contact, with the rigid-body's
+     *   collider, the other collider, and the world-space total contact force applied to the
+     *   rigid-body's collider during the last step.
+     */
+    public contactsWithRigidBody(
+        body: RigidBody,
+        f: (collider: Collider, otherCollider: Collider, force: Vector) => void,
+    ) {
+        this.narrowPhase.raw.contactsWithRigidBody(
+            this.bodies.raw,
+            body.handle,
+            this.timestep,
+            (
+                handle: ColliderHandle,
+                otherHandle: ColliderHandle,
+                rawForce: RawVector,
+            ) => {
+                f(
+                    this.colliders.get(handle),
+                    this.colliders.get(otherHandle),
+                    VectorOps.fromRaw(rawForce),
+                );
+            },
+        );
+    }
+
     /**
      * Enumerates all the colliders intersecting the given colliders, assuming one of them
      * is a sensor.
+     *
+     * This reports both sensor-sensor and sensor-solid overlaps, as long as the pair is allowed
+     * by the active collision types of both colliders. Only pairs that are currently intersecting
+     * are reported.
      */
     public intersectionsWith(
         collider1: Collider,
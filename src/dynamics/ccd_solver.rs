@@ -10,4 +10,9 @@ impl RawCCDSolver {
     pub fn new() -> Self {
         RawCCDSolver(CCDSolver::new())
     }
+
+    /// Resets this CCD solver.
+    pub fn clear(&mut self) {
+        self.0 = CCDSolver::new();
+    }
 }
@@ -61,6 +61,16 @@ impl RawImpulseJointSet {
         })
     }
 
+    /// The impulses applied by this joint during the last step.
+    ///
+    /// These are the impulses along each of the joint's constrained axes, expressed in the local
+    /// frame of the joint attached to the first rigid-body: first the linear impulses (in N·s,
+    /// one per linear axis), then the angular impulses (in N·m·s, one per angular axis). Free axes
+    /// have a zero impulse, except when a motor or limit is active on them.
+    pub fn jointImpulses(&self, handle: FlatHandle) -> Vec<f32> {
+        self.map(handle, |j| j.impulses.as_slice().to_vec())
+    }
+
     /// Are contacts between the rigid-bodies attached by this joint enabled?
     pub fn jointContactsEnabled(&self, handle: FlatHandle) -> bool {
         self.map(handle, |j| j.data.contacts_enabled)
@@ -8,9 +8,10 @@ pub struct RawImpulseJointSet(pub(crate) ImpulseJointSet);
 
 impl RawImpulseJointSet {
     pub(crate) fn map<T>(&self, handle: FlatHandle, f: impl FnOnce(&ImpulseJoint) -> T) -> T {
-        let body = self.0.get(utils::impulse_joint_handle(handle)).expect(
-            "Invalid ImpulseJoint reference. It may have been removed from the physics World.",
-        );
+        let body = self
+            .0
+            .get(utils::impulse_joint_handle(handle))
+            .unwrap_or_else(|| utils::throw_invalid_handle("ImpulseJoint"));
         f(body)
     }
 
@@ -19,9 +20,10 @@ impl RawImpulseJointSet {
         handle: FlatHandle,
         f: impl FnOnce(&mut ImpulseJoint) -> T,
     ) -> T {
-        let body = self.0.get_mut(utils::impulse_joint_handle(handle)).expect(
-            "Invalid ImpulseJoint reference. It may have been removed from the physics World.",
-        );
+        let body = self
+            .0
+            .get_mut(utils::impulse_joint_handle(handle))
+            .unwrap_or_else(|| utils::throw_invalid_handle("ImpulseJoint"));
         f(body)
     }
 }
@@ -33,6 +35,11 @@ impl RawImpulseJointSet {
         RawImpulseJointSet(ImpulseJointSet::new())
     }
 
+    /// Removes all the impulse joints from this set.
+    pub fn clear(&mut self) {
+        self.0 = ImpulseJointSet::new();
+    }
+
     pub fn createJoint(
         &mut self,
         params: &RawGenericJoint,
@@ -57,6 +64,33 @@ impl RawImpulseJointSet {
         self.0.remove(handle, wakeUp);
     }
 
+    /// Inserts a copy of every joint of `other` into this set.
+    ///
+    /// The joints are attached to the copies of their rigid-bodies given by `bodyRemap`, the
+    /// handle remap table returned by `RawRigidBodySet::mergeFrom`. Joints attached to a
+    /// rigid-body missing from this table are skipped. Returns a handle remap table made of one
+    /// `[oldHandle, newHandle]` pair per joint inserted.
+    pub fn mergeFrom(
+        &mut self,
+        other: &RawImpulseJointSet,
+        bodyRemap: Vec<FlatHandle>,
+    ) -> Vec<FlatHandle> {
+        let body_remap = utils::body_remap(&bodyRemap);
+        let mut remap = vec![];
+
+        for (handle, joint) in other.0.iter() {
+            if let (Some(body1), Some(body2)) =
+                (body_remap.get(&joint.body1), body_remap.get(&joint.body2))
+            {
+                let new_handle = self.0.insert(*body1, *body2, joint.data, true);
+                remap.push(utils::flat_handle(handle.0));
+                remap.push(utils::flat_handle(new_handle.0));
+            }
+        }
+
+        remap
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -68,7 +102,7 @@ impl RawImpulseJointSet {
     /// Applies the given JavaScript function to the integer handle of each joint managed by this physics world.
     ///
     /// # Parameters
-    /// - `f(handle)`: the function to apply to the integer handle of each joint managed by this set. Called as `f(collider)`.
+    /// - `f(handle)`: the function to apply to the integer handle of each joint managed by this set. Called as `f(handle)`.
     pub fn forEachJointHandle(&self, f: &js_sys::Function) {
         let this = JsValue::null();
         for (handle, _) in self.0.iter() {
@@ -79,7 +113,7 @@ impl RawImpulseJointSet {
     /// Applies the given JavaScript function to the integer handle of each joint attached to the given rigid-body.
     ///
     /// # Parameters
-    /// - `f(handle)`: the function to apply to the integer handle of each joint attached to the rigid-body. Called as `f(collider)`.
+    /// - `f(handle)`: the function to apply to the integer handle of each joint attached to the rigid-body. Called as `f(handle)`.
     pub fn forEachJointAttachedToRigidBody(&self, body: FlatHandle, f: &js_sys::Function) {
         let this = JsValue::null();
         for (_, _, handle, _) in self.0.attached_joints(utils::body_handle(body)) {
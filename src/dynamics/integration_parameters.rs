@@ -21,11 +21,32 @@ impl RawIntegrationParameters {
         self.0.erp
     }
 
+    #[wasm_bindgen(getter)]
+    pub fn dampingRatio(&self) -> f32 {
+        self.0.damping_ratio
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn jointErp(&self) -> f32 {
+        self.0.joint_erp
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn jointDampingRatio(&self) -> f32 {
+        self.0.joint_damping_ratio
+    }
+
+    #[wasm_bindgen(getter)]
+    pub fn maxPenetrationCorrection(&self) -> f32 {
+        self.0.max_penetration_correction
+    }
+
     #[wasm_bindgen(getter)]
     pub fn allowedLinearError(&self) -> f32 {
         self.0.allowed_linear_error
     }
 
+    /// The distance at which predictive contacts are generated, shared by all colliders.
     #[wasm_bindgen(getter)]
     pub fn predictionDistance(&self) -> f32 {
         self.0.prediction_distance
@@ -66,6 +87,26 @@ impl RawIntegrationParameters {
         self.0.erp = value
     }
 
+    #[wasm_bindgen(setter)]
+    pub fn set_dampingRatio(&mut self, value: f32) {
+        self.0.damping_ratio = value
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_jointErp(&mut self, value: f32) {
+        self.0.joint_erp = value
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_jointDampingRatio(&mut self, value: f32) {
+        self.0.joint_damping_ratio = value
+    }
+
+    #[wasm_bindgen(setter)]
+    pub fn set_maxPenetrationCorrection(&mut self, value: f32) {
+        self.0.max_penetration_correction = value
+    }
+
     #[wasm_bindgen(setter)]
     pub fn set_allowedLinearError(&mut self, value: f32) {
         self.0.allowed_linear_error = value
@@ -12,20 +12,35 @@ impl RawIslandManager {
         RawIslandManager(IslandManager::new())
     }
 
+    /// Removes all the islands and active rigid-bodies tracked by this island manager.
+    pub fn clear(&mut self) {
+        self.0 = IslandManager::new();
+    }
+
     /// Applies the given JavaScript function to the integer handle of each active rigid-body
     /// managed by this island manager.
     ///
+    /// Only dynamic rigid-bodies are visited: fixed and kinematic rigid-bodies are never
+    /// part of an island.
+    ///
     /// After a short time of inactivity, a rigid-body is automatically deactivated ("asleep") by
     /// the physics engine in order to save computational power. A sleeping rigid-body never moves
     /// unless it is moved manually by the user.
     ///
     /// # Parameters
     /// - `f(handle)`: the function to apply to the integer handle of each active rigid-body managed by this
-    ///   set. Called as `f(collider)`.
+    ///   set. Called as `f(handle)`.
     pub fn forEachActiveRigidBodyHandle(&self, f: &js_sys::Function) {
         let this = JsValue::null();
         for handle in self.0.active_dynamic_bodies() {
             let _ = f.call1(&this, &JsValue::from(utils::flat_handle(handle.0)));
         }
     }
+
+    /// The number of active dynamic rigid-bodies managed by this island manager.
+    ///
+    /// This is the number of handles visited by `forEachActiveRigidBodyHandle`.
+    pub fn numActiveRigidBodies(&self) -> usize {
+        self.0.active_dynamic_bodies().len()
+    }
 }
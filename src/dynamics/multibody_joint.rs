@@ -1,6 +1,6 @@
 use crate::dynamics::{RawJointAxis, RawJointType, RawMultibodyJointSet};
 use crate::math::{RawRotation, RawVector};
-use crate::utils::FlatHandle;
+use crate::utils::{self, FlatHandle};
 use rapier::dynamics::JointAxis;
 use wasm_bindgen::prelude::*;
 
@@ -11,15 +11,25 @@ impl RawMultibodyJointSet {
         self.map(handle, |j| j.data.locked_axes.into())
     }
 
-    // /// The unique integer identifier of the first rigid-body this joint it attached to.
-    // pub fn jointBodyHandle1(&self, handle: FlatHandle) -> u32 {
-    //     self.map(handle, |j| j.body1.into_raw_parts().0)
-    // }
-    //
-    // /// The unique integer identifier of the second rigid-body this joint is attached to.
-    // pub fn jointBodyHandle2(&self, handle: FlatHandle) -> u32 {
-    //     self.map(handle, |j| j.body2.into_raw_parts().0)
-    // }
+    /// The unique integer identifier of the first rigid-body this joint it attached to.
+    ///
+    /// This is the rigid-body of the parent link in the multibody.
+    pub fn jointBodyHandle1(&self, handle: FlatHandle) -> FlatHandle {
+        self.map_link(handle, |multibody, link| {
+            let parent = link
+                .parent_id()
+                .and_then(|id| multibody.link(id))
+                .unwrap_or(link);
+            utils::flat_handle(parent.rigid_body_handle().0)
+        })
+    }
+
+    /// The unique integer identifier of the second rigid-body this joint is attached to.
+    pub fn jointBodyHandle2(&self, handle: FlatHandle) -> FlatHandle {
+        self.map_link(handle, |_, link| {
+            utils::flat_handle(link.rigid_body_handle().0)
+        })
+    }
 
     /// The angular part of the joint’s local frame relative to the first rigid-body it is attached to.
     pub fn jointFrameX1(&self, handle: FlatHandle) -> RawRotation {
@@ -1,6 +1,6 @@
 use crate::dynamics::RawGenericJoint;
 use crate::utils::{self, FlatHandle};
-use rapier::dynamics::{MultibodyJoint, MultibodyJointSet};
+use rapier::dynamics::{Multibody, MultibodyJoint, MultibodyJointSet, MultibodyLink};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -11,7 +11,7 @@ impl RawMultibodyJointSet {
         let (body, link_id) = self
             .0
             .get(utils::multibody_joint_handle(handle))
-            .expect("Invalid Joint reference. It may have been removed from the physics World.");
+            .unwrap_or_else(|| utils::throw_invalid_handle("Joint"));
         f(body.link(link_id).unwrap().joint())
     }
 
@@ -23,9 +23,22 @@ impl RawMultibodyJointSet {
         let (body, link_id) = self
             .0
             .get_mut(utils::multibody_joint_handle(handle))
-            .expect("Invalid Joint reference. It may have been removed from the physics World.");
+            .unwrap_or_else(|| utils::throw_invalid_handle("Joint"));
         f(&mut body.link_mut(link_id).unwrap().joint)
     }
+
+    // Applies `f` to the multibody containing the given joint, and to the link it is attached to.
+    pub(crate) fn map_link<T>(
+        &self,
+        handle: FlatHandle,
+        f: impl FnOnce(&Multibody, &MultibodyLink) -> T,
+    ) -> T {
+        let (body, link_id) = self
+            .0
+            .get(utils::multibody_joint_handle(handle))
+            .unwrap_or_else(|| utils::throw_invalid_handle("Joint"));
+        f(body, body.link(link_id).unwrap())
+    }
 }
 
 #[wasm_bindgen]
@@ -35,21 +48,28 @@ impl RawMultibodyJointSet {
         RawMultibodyJointSet(MultibodyJointSet::new())
     }
 
+    /// Removes all the multibody joints from this set.
+    pub fn clear(&mut self) {
+        self.0 = MultibodyJointSet::new();
+    }
+
+    /// Creates a new multibody joint between two rigid-bodies.
+    ///
+    /// Returns `None` if the joint can't be added to a multibody, e.g., because it would
+    /// create a kinematic loop, or because the second rigid-body already has a parent link.
     pub fn createJoint(
         &mut self,
         params: &RawGenericJoint,
         parent1: FlatHandle,
         parent2: FlatHandle,
         wakeUp: bool,
-    ) -> FlatHandle {
-        // TODO: avoid the unwrap?
+    ) -> Option<FlatHandle> {
         let parent1 = utils::body_handle(parent1);
         let parent2 = utils::body_handle(parent2);
 
         self.0
             .insert(parent1, parent2, params.0.clone(), wakeUp)
             .map(|h| utils::flat_handle(h.0))
-            .unwrap_or(FlatHandle::MAX)
     }
 
     pub fn remove(&mut self, handle: FlatHandle, wakeUp: bool) {
@@ -57,6 +77,50 @@ impl RawMultibodyJointSet {
         self.0.remove(handle, wakeUp);
     }
 
+    /// Inserts a copy of every multibody joint of `other` into this set.
+    ///
+    /// The joints are attached to the copies of their rigid-bodies given by `bodyRemap`, the
+    /// handle remap table returned by `RawRigidBodySet::mergeFrom`. Joints attached to a
+    /// rigid-body missing from this table are skipped. Returns a handle remap table made of one
+    /// `[oldHandle, newHandle]` pair per joint inserted.
+    pub fn mergeFrom(
+        &mut self,
+        other: &RawMultibodyJointSet,
+        bodyRemap: Vec<FlatHandle>,
+    ) -> Vec<FlatHandle> {
+        let body_remap = utils::body_remap(&bodyRemap);
+        let mut remap = vec![];
+
+        for (handle, ..) in other.0.iter() {
+            let (multibody, link_id) = match other.0.get(handle) {
+                Some(link) => link,
+                None => continue,
+            };
+            let link = multibody.link(link_id).unwrap();
+            let parent = match link.parent_id().and_then(|id| multibody.link(id)) {
+                Some(parent) => parent,
+                None => continue,
+            };
+
+            if let (Some(body1), Some(body2)) = (
+                body_remap.get(&parent.rigid_body_handle()),
+                body_remap.get(&link.rigid_body_handle()),
+            ) {
+                if let Some(new_handle) = self.0.insert(*body1, *body2, link.joint().data, true) {
+                    remap.push(utils::flat_handle(handle.0));
+                    remap.push(utils::flat_handle(new_handle.0));
+                }
+            }
+        }
+
+        remap
+    }
+
+    /// The number of multibody joints on this set.
+    pub fn len(&self) -> usize {
+        self.0.iter().count()
+    }
+
     pub fn contains(&self, handle: FlatHandle) -> bool {
         self.0.get(utils::multibody_joint_handle(handle)).is_some()
     }
@@ -64,7 +128,7 @@ impl RawMultibodyJointSet {
     /// Applies the given JavaScript function to the integer handle of each joint managed by this physics world.
     ///
     /// # Parameters
-    /// - `f(handle)`: the function to apply to the integer handle of each joint managed by this set. Called as `f(collider)`.
+    /// - `f(handle)`: the function to apply to the integer handle of each joint managed by this set. Called as `f(handle)`.
     pub fn forEachJointHandle(&self, f: &js_sys::Function) {
         let this = JsValue::null();
         for (handle, _, _) in self.0.iter() {
@@ -75,7 +139,7 @@ impl RawMultibodyJointSet {
     /// Applies the given JavaScript function to the integer handle of each joint attached to the given rigid-body.
     ///
     /// # Parameters
-    /// - `f(handle)`: the function to apply to the integer handle of each joint attached to the rigid-body. Called as `f(collider)`.
+    /// - `f(handle)`: the function to apply to the integer handle of each joint attached to the rigid-body. Called as `f(handle)`.
     pub fn forEachJointAttachedToRigidBody(&self, body: FlatHandle, f: &js_sys::Function) {
         let this = JsValue::null();
         for (_, _, handle) in self.0.attached_joints(utils::body_handle(body)) {
@@ -1,12 +1,85 @@
 use crate::dynamics::{RawRigidBodySet, RawRigidBodyType};
-use crate::geometry::RawColliderSet;
+use crate::geometry::{self, RawColliderSet};
 #[cfg(feature = "dim3")]
 use crate::math::RawSdpMatrix3;
 use crate::math::{RawRotation, RawVector};
 use crate::utils::{self, FlatHandle};
-use rapier::dynamics::MassProperties;
+use rapier::dynamics::{
+    MassProperties, RigidBody, RigidBodyActivation, RigidBodyPosition, RigidBodyType,
+    RigidBodyVelocity,
+};
+use rapier::geometry::{Aabb, Collider};
+use rapier::math::{Isometry, Point};
+use rapier::parry::bounding_volume::BoundingVolume;
 use wasm_bindgen::prelude::*;
 
+// The velocity Rapier will derive from the current and next positions of a position-based
+// kinematic rigid-body during a step of length `dt`.
+fn kinematic_velocity(rb: &RigidBody, dt: f32) -> Option<RigidBodyVelocity> {
+    if rb.body_type() != RigidBodyType::KinematicPositionBased || dt <= 0.0 {
+        return None;
+    }
+
+    let pos = RigidBodyPosition {
+        position: *rb.position(),
+        next_position: *rb.next_position(),
+    };
+    Some(pos.interpolate_velocity(1.0 / dt, &rb.mass_properties().local_mprops.local_com))
+}
+
+impl RawRigidBodySet {
+    // The union of the world-space AABBs of all the colliders attached to this rigid-body.
+    fn aabb(&self, handle: FlatHandle, colliders: &RawColliderSet) -> Option<Aabb> {
+        self.map(handle, |rb| {
+            rb.colliders()
+                .iter()
+                .filter_map(|h| colliders.0.get(*h))
+                .map(|co| co.compute_aabb())
+                .reduce(|aabb1, aabb2| aabb1.merged(&aabb2))
+        })
+    }
+
+    // The union of the AABBs of all the colliders attached to this rigid-body, swept from their
+    // current position to the one they will have after a step of length `dt`.
+    fn swept_aabb(&self, handle: FlatHandle, colliders: &RawColliderSet, dt: f32) -> Option<Aabb> {
+        self.map(handle, |rb| {
+            let next_pos = if rb.body_type() == RigidBodyType::KinematicPositionBased {
+                *rb.next_position()
+            } else {
+                rb.predict_position_using_velocity_and_forces(dt)
+            };
+
+            rb.colliders()
+                .iter()
+                .filter_map(|h| colliders.0.get(*h))
+                .map(|co| {
+                    let pos_wrt_parent = co
+                        .position_wrt_parent()
+                        .copied()
+                        .unwrap_or_else(Isometry::identity);
+                    co.compute_swept_aabb(&(next_pos * pos_wrt_parent))
+                })
+                .reduce(|aabb1, aabb2| aabb1.merged(&aabb2))
+        })
+    }
+
+    // Applies `f` to each collider attached to this rigid-body.
+    fn for_each_collider_mut(
+        &self,
+        handle: FlatHandle,
+        colliders: &mut RawColliderSet,
+        mut f: impl FnMut(&mut Collider),
+    ) {
+        self.map(handle, |rb| {
+            for h in rb.colliders() {
+                if let Some(co) = colliders.0.get_mut(*h) {
+                    f(co);
+                }
+            }
+        })
+    }
+}
+
 #[wasm_bindgen]
 impl RawRigidBodySet {
     /// The world-space translation of this rigid-body.
@@ -20,6 +93,10 @@ impl RawRigidBodySet {
     }
 
     /// Put the given rigid-body to sleep.
+    ///
+    /// Its velocities are set to zero and it is excluded from the next simulation step, including
+    /// if it was just inserted. Rigid-bodies can also be created asleep with the `sleeping`
+    /// argument of `createRigidBody`.
     pub fn rbSleep(&mut self, handle: FlatHandle) {
         self.map_mut(handle, |rb| rb.sleep());
     }
@@ -29,6 +106,53 @@ impl RawRigidBodySet {
         self.map(handle, |rb| rb.is_sleeping())
     }
 
+    /// Sets whether this rigid-body can be put to sleep automatically.
+    ///
+    /// Disabling sleeping wakes the rigid-body up. Enabling it restores the default sleep
+    /// thresholds.
+    pub fn rbSetCanSleep(&mut self, handle: FlatHandle, canSleep: bool) {
+        self.map_mut(handle, |rb| {
+            let activation = rb.activation_mut();
+            if canSleep {
+                activation.linear_threshold = RigidBodyActivation::default_linear_threshold();
+                activation.angular_threshold = RigidBodyActivation::default_angular_threshold();
+            } else {
+                activation.linear_threshold = -1.0;
+                activation.angular_threshold = -1.0;
+                rb.wake_up(true);
+            }
+        })
+    }
+
+    /// Can this rigid-body be put to sleep automatically?
+    pub fn rbCanSleep(&self, handle: FlatHandle) -> bool {
+        self.map(handle, |rb| {
+            rb.activation().linear_threshold >= 0.0 && rb.activation().angular_threshold >= 0.0
+        })
+    }
+
+    /// Sets the velocity thresholds below which this rigid-body can be put to sleep.
+    ///
+    /// A rigid-body falls asleep once both its linear and angular velocities stayed below these
+    /// thresholds for a short while. A negative threshold prevents the rigid-body from sleeping.
+    pub fn rbSetSleepThresholds(&mut self, handle: FlatHandle, linear: f32, angular: f32) {
+        self.map_mut(handle, |rb| {
+            let activation = rb.activation_mut();
+            activation.linear_threshold = linear;
+            activation.angular_threshold = angular;
+        })
+    }
+
+    /// The linear velocity threshold below which this rigid-body can be put to sleep.
+    pub fn rbLinearSleepThreshold(&self, handle: FlatHandle) -> f32 {
+        self.map(handle, |rb| rb.activation().linear_threshold)
+    }
+
+    /// The angular velocity threshold below which this rigid-body can be put to sleep.
+    pub fn rbAngularSleepThreshold(&self, handle: FlatHandle) -> f32 {
+        self.map(handle, |rb| rb.activation().angular_threshold)
+    }
+
     /// Is the velocity of this rigid-body not zero?
     pub fn rbIsMoving(&self, handle: FlatHandle) -> bool {
         self.map(handle, |rb| rb.is_moving())
@@ -94,6 +218,8 @@ impl RawRigidBodySet {
     /// - `w`: the scalar component of the quaternion.
     /// - `wakeUp`: forces the rigid-body to wake-up so it is properly affected by forces if it
     /// wasn't moving before modifying its position.
+    ///
+    /// Returns `false`, without modifying the rigid-body, if the given quaternion is zero.
     #[cfg(feature = "dim3")]
     pub fn rbSetRotation(
         &mut self,
@@ -103,9 +229,12 @@ impl RawRigidBodySet {
         z: f32,
         w: f32,
         wakeUp: bool,
-    ) {
+    ) -> bool {
         if let Some(q) = na::Unit::try_new(na::Quaternion::new(w, x, y, z), 0.0) {
-            self.map_mut(handle, |rb| rb.set_rotation(q, wakeUp))
+            self.map_mut(handle, |rb| rb.set_rotation(q, wakeUp));
+            true
+        } else {
+            false
         }
     }
 
@@ -228,6 +357,85 @@ impl RawRigidBodySet {
         })
     }
 
+    /// Teleports this rigid-body to the given position.
+    ///
+    /// Both the current and next positions are set, so no artificial velocity is derived from
+    /// the jump, whatever the rigid-body type. The linear and angular velocities are left
+    /// unchanged.
+    ///
+    /// # Parameters
+    /// - `translation`: the world-space translation of the rigid-body.
+    /// - `rotation`: the world-space rotation of the rigid-body.
+    /// - `wakeUp`: forces the rigid-body to wake-up so it is properly affected by forces if it
+    /// wasn't moving before modifying its position.
+    pub fn rbTeleport(
+        &mut self,
+        handle: FlatHandle,
+        translation: &RawVector,
+        rotation: &RawRotation,
+        wakeUp: bool,
+    ) {
+        let pos = Isometry::from_parts(translation.0.into(), rotation.0);
+        self.map_mut(handle, |rb| {
+            rb.set_position(pos, wakeUp);
+            if rb.is_kinematic() {
+                rb.set_next_kinematic_position(pos);
+            }
+        })
+    }
+
+    /// Moves this rigid-body by integrating its current linear and angular velocities over `dt`.
+    ///
+    /// This is a purely positional update, e.g., to extrapolate a remote rigid-body between two
+    /// authoritative updates: forces, gravity, contacts, and joints are ignored, and the
+    /// velocities are left unchanged. The rotation happens around the center of mass. The
+    /// attached colliders are moved at the next step, or by
+    /// `propagateModifiedBodyPositionsToColliders`.
+    pub fn rbIntegrateVelocities(&mut self, handle: FlatHandle, dt: f32) {
+        self.map_mut(handle, |rb| {
+            let vels = RigidBodyVelocity {
+                linvel: *rb.linvel(),
+                #[cfg(feature = "dim2")]
+                angvel: rb.angvel(),
+                #[cfg(feature = "dim3")]
+                angvel: *rb.angvel(),
+            };
+            let local_com = rb.mass_properties().local_mprops.local_com;
+            let pos = vels.integrate(dt, rb.position(), &local_com);
+
+            rb.set_position(pos, false);
+            if rb.is_kinematic() {
+                rb.set_next_kinematic_position(pos);
+            }
+        })
+    }
+
+    /// If this rigid body is kinematic, sets both its future translation and rotation after the
+    /// next timestep integration.
+    ///
+    /// This is equivalent to calling `rbSetNextKinematicTranslation` and
+    /// `rbSetNextKinematicRotation`, with a single rigid-body lookup.
+    ///
+    /// # Parameters
+    /// - `translation`: the world-space next translation of the rigid-body.
+    /// - `rotation`: the world-space next rotation of the rigid-body.
+    pub fn rbSetNextKinematicPosition(
+        &mut self,
+        handle: FlatHandle,
+        translation: &RawVector,
+        rotation: &RawRotation,
+    ) {
+        let pos = Isometry::from_parts(translation.0.into(), rotation.0);
+        self.map_mut(handle, |rb| {
+            rb.set_next_kinematic_position(pos);
+        })
+    }
+
+    /// Recomputes the mass, center of mass, and angular inertia of this rigid-body from its
+    /// attached colliders.
+    ///
+    /// This happens automatically at the next step, but can be called to read up-to-date mass
+    /// properties right after changing the shape or density of an attached collider.
     pub fn rbRecomputeMassPropertiesFromColliders(
         &mut self,
         handle: FlatHandle,
@@ -244,6 +452,11 @@ impl RawRigidBodySet {
         })
     }
 
+    /// Sets the additional mass properties of this rigid-body, added to the mass properties
+    /// computed from its attached colliders.
+    ///
+    /// The center of mass is expressed in the local-space of the rigid-body. This overrides any
+    /// additional mass or mass properties previously set on this rigid-body.
     #[cfg(feature = "dim3")]
     pub fn rbSetAdditionalMassProperties(
         &mut self,
@@ -265,6 +478,11 @@ impl RawRigidBodySet {
         })
     }
 
+    /// Sets the additional mass properties of this rigid-body, added to the mass properties
+    /// computed from its attached colliders.
+    ///
+    /// The center of mass is expressed in the local-space of the rigid-body. This overrides any
+    /// additional mass or mass properties previously set on this rigid-body.
     #[cfg(feature = "dim2")]
     pub fn rbSetAdditionalMassProperties(
         &mut self,
@@ -297,6 +515,51 @@ impl RawRigidBodySet {
         self.map(handle, |rb| RawVector(*rb.angvel()))
     }
 
+    /// The linear velocity this rigid-body will have during the next step of length `dt`.
+    ///
+    /// For a position-based kinematic rigid-body, this is the velocity derived from its current
+    /// position and the position set with `rbSetNextKinematicTranslation`. For other rigid-bodies,
+    /// this is the same as `rbLinvel`.
+    pub fn rbKinematicLinvel(&self, handle: FlatHandle, dt: f32) -> RawVector {
+        self.map(handle, |rb| {
+            RawVector(
+                kinematic_velocity(rb, dt)
+                    .map(|vels| vels.linvel)
+                    .unwrap_or(*rb.linvel()),
+            )
+        })
+    }
+
+    /// The angular velocity this rigid-body will have during the next step of length `dt`.
+    ///
+    /// For a position-based kinematic rigid-body, this is the velocity derived from its current
+    /// rotation and the rotation set with `rbSetNextKinematicRotation`. For other rigid-bodies,
+    /// this is the same as `rbAngvel`.
+    #[cfg(feature = "dim2")]
+    pub fn rbKinematicAngvel(&self, handle: FlatHandle, dt: f32) -> f32 {
+        self.map(handle, |rb| {
+            kinematic_velocity(rb, dt)
+                .map(|vels| vels.angvel)
+                .unwrap_or(rb.angvel())
+        })
+    }
+
+    /// The angular velocity this rigid-body will have during the next step of length `dt`.
+    ///
+    /// For a position-based kinematic rigid-body, this is the velocity derived from its current
+    /// rotation and the rotation set with `rbSetNextKinematicRotation`. For other rigid-bodies,
+    /// this is the same as `rbAngvel`.
+    #[cfg(feature = "dim3")]
+    pub fn rbKinematicAngvel(&self, handle: FlatHandle, dt: f32) -> RawVector {
+        self.map(handle, |rb| {
+            RawVector(
+                kinematic_velocity(rb, dt)
+                    .map(|vels| vels.angvel)
+                    .unwrap_or(*rb.angvel()),
+            )
+        })
+    }
+
     pub fn rbLockTranslations(&mut self, handle: FlatHandle, locked: bool, wake_up: bool) {
         self.map_mut(handle, |rb| rb.lock_translations(locked, wake_up))
     }
@@ -346,14 +609,20 @@ impl RawRigidBodySet {
         })
     }
 
+    /// The dominance group this rigid-body is part of.
     pub fn rbDominanceGroup(&self, handle: FlatHandle) -> i8 {
         self.map(handle, |rb| rb.dominance_group())
     }
 
+    /// Sets the dominance group of this rigid-body.
+    ///
+    /// A dynamic rigid-body with a higher dominance group behaves as if it had an infinite mass
+    /// when it interacts with a dynamic rigid-body with a lower dominance group.
     pub fn rbSetDominanceGroup(&mut self, handle: FlatHandle, group: i8) {
         self.map_mut(handle, |rb| rb.set_dominance_group(group))
     }
 
+    /// Enables or disables Continuous Collision Detection for this rigid-body.
     pub fn rbEnableCcd(&mut self, handle: FlatHandle, enabled: bool) {
         self.map_mut(handle, |rb| rb.enable_ccd(enabled))
     }
@@ -363,6 +632,22 @@ impl RawRigidBodySet {
         self.map(handle, |rb| rb.mass())
     }
 
+    /// The kinetic energy of this rigid-body.
+    ///
+    /// This is the sum of its translational energy `0.5 * m * v²` and rotational energy, computed
+    /// from its current velocities and its effective mass and angular inertia.
+    pub fn rbKineticEnergy(&self, handle: FlatHandle) -> f32 {
+        self.map(handle, |rb| rb.kinetic_energy())
+    }
+
+    /// The velocity of the given world-space point on this rigid-body.
+    ///
+    /// This is the linear velocity plus the contribution of the angular velocity at the given
+    /// point, relative to the world-space center of mass.
+    pub fn rbVelocityAtPoint(&self, handle: FlatHandle, point: &RawVector) -> RawVector {
+        self.map(handle, |rb| rb.velocity_at_point(&point.0.into()).into())
+    }
+
     /// The inverse of the mass of a rigid-body.
     ///
     /// If this is zero, the rigid-body is assumed to have infinite mass.
@@ -387,7 +672,7 @@ impl RawRigidBodySet {
         self.map(handle, |rb| rb.mass_properties().world_com.into())
     }
 
-    /// The inverse of the principal angular inertia of the rigid-body.
+    /// The square-root of the inverse of the principal angular inertia of the rigid-body.
     ///
     /// Components set to zero are assumed to be infinite along the corresponding principal axis.
     #[cfg(feature = "dim2")]
@@ -400,7 +685,7 @@ impl RawRigidBodySet {
         })
     }
 
-    /// The inverse of the principal angular inertia of the rigid-body.
+    /// The square-root of the inverse of the principal angular inertia of the rigid-body.
     ///
     /// Components set to zero are assumed to be infinite along the corresponding principal axis.
     #[cfg(feature = "dim3")]
@@ -493,6 +778,14 @@ impl RawRigidBodySet {
         self.map(handle, |rb| rb.is_ccd_enabled())
     }
 
+    /// Is Continuous Collision Detection active for this rigid-body during the current step?
+    ///
+    /// This is `true` only if CCD is enabled and the body moved fast enough during the last step
+    /// to require it.
+    pub fn rbIsCcdActive(&self, handle: FlatHandle) -> bool {
+        self.map(handle, |rb| rb.is_ccd_active())
+    }
+
     /// The number of colliders attached to this rigid-body.
     pub fn rbNumColliders(&self, handle: FlatHandle) -> usize {
         self.map(handle, |rb| rb.colliders().len())
@@ -507,6 +800,91 @@ impl RawRigidBodySet {
         self.map(handle, |rb| utils::flat_handle(rb.colliders()[at].0))
     }
 
+    /// The lower bound of the world-space AABB of all the colliders attached to this rigid-body.
+    ///
+    /// Returns `None` if no collider is attached to this rigid-body.
+    pub fn rbAabbMin(&self, handle: FlatHandle, colliders: &RawColliderSet) -> Option<RawVector> {
+        self.aabb(handle, colliders).map(|aabb| aabb.mins.into())
+    }
+
+    /// The upper bound of the world-space AABB of all the colliders attached to this rigid-body.
+    ///
+    /// Returns `None` if no collider is attached to this rigid-body.
+    pub fn rbAabbMax(&self, handle: FlatHandle, colliders: &RawColliderSet) -> Option<RawVector> {
+        self.aabb(handle, colliders).map(|aabb| aabb.maxs.into())
+    }
+
+    /// The lower bound of the AABB of all the colliders attached to this rigid-body, swept over
+    /// a step of length `dt`.
+    ///
+    /// The end position is predicted from the current velocities and forces of the rigid-body,
+    /// or is its next kinematic position for a position-based kinematic rigid-body. Returns
+    /// `None` if no collider is attached to this rigid-body.
+    pub fn rbSweptAabbMin(
+        &self,
+        handle: FlatHandle,
+        colliders: &RawColliderSet,
+        dt: f32,
+    ) -> Option<RawVector> {
+        self.swept_aabb(handle, colliders, dt)
+            .map(|aabb| aabb.mins.into())
+    }
+
+    /// The upper bound of the AABB of all the colliders attached to this rigid-body, swept over
+    /// a step of length `dt`.
+    ///
+    /// See `rbSweptAabbMin` for details.
+    pub fn rbSweptAabbMax(
+        &self,
+        handle: FlatHandle,
+        colliders: &RawColliderSet,
+        dt: f32,
+    ) -> Option<RawVector> {
+        self.swept_aabb(handle, colliders, dt)
+            .map(|aabb| aabb.maxs.into())
+    }
+
+    /// Sets the collision groups of all the colliders attached to this rigid-body.
+    ///
+    /// Colliders attached to this rigid-body afterwards keep their own collision groups.
+    pub fn rbSetCollisionGroups(
+        &self,
+        handle: FlatHandle,
+        groups: u32,
+        colliders: &mut RawColliderSet,
+    ) {
+        let groups = geometry::unpack_interaction_groups(groups);
+        self.for_each_collider_mut(handle, colliders, |co| co.set_collision_groups(groups))
+    }
+
+    /// Sets the solver groups of all the colliders attached to this rigid-body.
+    ///
+    /// Colliders attached to this rigid-body afterwards keep their own solver groups.
+    pub fn rbSetSolverGroups(
+        &self,
+        handle: FlatHandle,
+        groups: u32,
+        colliders: &mut RawColliderSet,
+    ) {
+        let groups = geometry::unpack_interaction_groups(groups);
+        self.for_each_collider_mut(handle, colliders, |co| co.set_solver_groups(groups))
+    }
+
+    /// Sets the friction coefficient of all the colliders attached to this rigid-body.
+    pub fn rbSetFriction(&self, handle: FlatHandle, friction: f32, colliders: &mut RawColliderSet) {
+        self.for_each_collider_mut(handle, colliders, |co| co.set_friction(friction))
+    }
+
+    /// Sets the restitution coefficient of all the colliders attached to this rigid-body.
+    pub fn rbSetRestitution(
+        &self,
+        handle: FlatHandle,
+        restitution: f32,
+        colliders: &mut RawColliderSet,
+    ) {
+        self.for_each_collider_mut(handle, colliders, |co| co.set_restitution(restitution))
+    }
+
     /// The status of this rigid-body: fixed, dynamic, or kinematic.
     pub fn rbBodyType(&self, handle: FlatHandle) -> RawRigidBodyType {
         self.map(handle, |rb| rb.body_type().into())
@@ -550,10 +928,71 @@ impl RawRigidBodySet {
         self.map_mut(handle, |rb| rb.set_angular_damping(factor));
     }
 
+    /// Sets the maximum linear speed of this rigid-body.
+    ///
+    /// If its linear velocity is larger than this before or after a simulation step (or
+    /// substep), it is scaled down to this magnitude. Only dynamic rigid-bodies are affected. Set
+    /// it to `Infinity` to remove the cap. Velocity caps are part of world snapshots.
+    ///
+    /// Throws if `max` is negative or NaN.
+    pub fn rbSetMaxLinvel(&mut self, handle: FlatHandle, max: f32) {
+        let handle = utils::body_handle(handle);
+        if !self.0.contains(handle) {
+            utils::throw_invalid_handle("RigidBody");
+        }
+        if !(max >= 0.0) {
+            wasm_bindgen::throw_str("The maximum linear speed must be non-negative.");
+        }
+        self.1.entry(handle).or_default().max_linvel = max;
+    }
+
+    /// Sets the maximum angular speed of this rigid-body.
+    ///
+    /// If its angular velocity is larger than this before or after a simulation step (or
+    /// substep), it is scaled down to this magnitude. Only dynamic rigid-bodies are affected. Set
+    /// it to `Infinity` to remove the cap. Velocity caps are part of world snapshots.
+    ///
+    /// Throws if `max` is negative or NaN.
+    pub fn rbSetMaxAngvel(&mut self, handle: FlatHandle, max: f32) {
+        let handle = utils::body_handle(handle);
+        if !self.0.contains(handle) {
+            utils::throw_invalid_handle("RigidBody");
+        }
+        if !(max >= 0.0) {
+            wasm_bindgen::throw_str("The maximum angular speed must be non-negative.");
+        }
+        self.1.entry(handle).or_default().max_angvel = max;
+    }
+
+    /// The maximum linear speed of this rigid-body, or `Infinity` if it isn't capped.
+    pub fn rbMaxLinvel(&self, handle: FlatHandle) -> f32 {
+        self.map(handle, |_| {
+            self.1
+                .get(&utils::body_handle(handle))
+                .map(|limits| limits.max_linvel)
+                .unwrap_or(f32::INFINITY)
+        })
+    }
+
+    /// The maximum angular speed of this rigid-body, or `Infinity` if it isn't capped.
+    pub fn rbMaxAngvel(&self, handle: FlatHandle) -> f32 {
+        self.map(handle, |_| {
+            self.1
+                .get(&utils::body_handle(handle))
+                .map(|limits| limits.max_angvel)
+                .unwrap_or(f32::INFINITY)
+        })
+    }
+
+    /// Enables or disables this rigid-body.
+    ///
+    /// A disabled rigid-body and its attached colliders are excluded from the simulation and
+    /// from scene queries, but keep their handle, position, and velocities until re-enabled.
     pub fn rbSetEnabled(&mut self, handle: FlatHandle, enabled: bool) {
         self.map_mut(handle, |rb| rb.set_enabled(enabled))
     }
 
+    /// Is this rigid-body enabled?
     pub fn rbIsEnabled(&self, handle: FlatHandle) -> bool {
         self.map(handle, |rb| rb.is_enabled())
     }
@@ -566,7 +1005,34 @@ impl RawRigidBodySet {
         self.map_mut(handle, |rb| rb.set_gravity_scale(factor, wakeUp));
     }
 
+    /// The sum of the user-added forces currently applied to this rigid-body.
+    ///
+    /// This is the force that will be applied at the next step, as accumulated by `rbAddForce`
+    /// and `rbAddForceAtPoint`.
+    pub fn rbUserForce(&self, handle: FlatHandle) -> RawVector {
+        self.map(handle, |rb| RawVector(rb.user_force()))
+    }
+
+    /// The sum of the user-added torques currently applied to this rigid-body.
+    ///
+    /// This includes the torques induced by `rbAddForceAtPoint`.
+    #[cfg(feature = "dim2")]
+    pub fn rbUserTorque(&self, handle: FlatHandle) -> f32 {
+        self.map(handle, |rb| rb.user_torque())
+    }
+
+    /// The sum of the user-added torques currently applied to this rigid-body.
+    ///
+    /// This includes the torques induced by `rbAddForceAtPoint`.
+    #[cfg(feature = "dim3")]
+    pub fn rbUserTorque(&self, handle: FlatHandle) -> RawVector {
+        self.map(handle, |rb| RawVector(rb.user_torque()))
+    }
+
     /// Resets to zero all user-added forces added to this rigid-body.
+    ///
+    /// User-added forces are not cleared automatically by the simulation step: they keep being
+    /// applied at each step until this is called.
     pub fn rbResetForces(&mut self, handle: FlatHandle, wakeUp: bool) {
         self.map_mut(handle, |rb| {
             rb.reset_forces(wakeUp);
@@ -574,6 +1040,9 @@ impl RawRigidBodySet {
     }
 
     /// Resets to zero all user-added torques added to this rigid-body.
+    ///
+    /// User-added torques are not cleared automatically by the simulation step: they keep being
+    /// applied at each step until this is called.
     pub fn rbResetTorques(&mut self, handle: FlatHandle, wakeUp: bool) {
         self.map_mut(handle, |rb| {
             rb.reset_torques(wakeUp);
@@ -691,6 +1160,46 @@ impl RawRigidBodySet {
         })
     }
 
+    /// Adds a force at the given local-space point of this rigid-body.
+    ///
+    /// # Parameters
+    /// - `force`: the world-space force to apply on the rigid-body.
+    /// - `point`: the point where the force is to be applied, expressed in the local-space of the
+    ///   rigid-body.
+    /// - `wakeUp`: should the rigid-body be automatically woken-up?
+    pub fn rbAddForceAtLocalPoint(
+        &mut self,
+        handle: FlatHandle,
+        force: &RawVector,
+        point: &RawVector,
+        wakeUp: bool,
+    ) {
+        self.map_mut(handle, |rb| {
+            let world_point = rb.position() * Point::from(point.0);
+            rb.add_force_at_point(force.0, world_point, wakeUp);
+        })
+    }
+
+    /// Applies an impulse at the given local-space point of this rigid-body.
+    ///
+    /// # Parameters
+    /// - `impulse`: the world-space impulse to apply on the rigid-body.
+    /// - `point`: the point where the impulse is to be applied, expressed in the local-space of
+    ///   the rigid-body.
+    /// - `wakeUp`: should the rigid-body be automatically woken-up?
+    pub fn rbApplyImpulseAtLocalPoint(
+        &mut self,
+        handle: FlatHandle,
+        impulse: &RawVector,
+        point: &RawVector,
+        wakeUp: bool,
+    ) {
+        self.map_mut(handle, |rb| {
+            let world_point = rb.position() * Point::from(point.0);
+            rb.apply_impulse_at_point(impulse.0, world_point, wakeUp);
+        })
+    }
+
     /// An arbitrary user-defined 32-bit integer
     pub fn rbUserData(&self, handle: FlatHandle) -> u32 {
         self.map(handle, |rb| rb.user_data as u32)
@@ -2,7 +2,11 @@ use crate::dynamics::{RawImpulseJointSet, RawIslandManager, RawMultibodyJointSet
 use crate::geometry::RawColliderSet;
 use crate::math::{RawRotation, RawVector};
 use crate::utils::{self, FlatHandle};
-use rapier::dynamics::{MassProperties, RigidBody, RigidBodyBuilder, RigidBodySet, RigidBodyType};
+use rapier::dynamics::{
+    MassProperties, RigidBody, RigidBodyBuilder, RigidBodyHandle, RigidBodySet, RigidBodyType,
+};
+use rapier::math::{Isometry, Real, Vector, DIM};
+use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -35,14 +39,38 @@ impl Into<RawRigidBodyType> for RigidBodyType {
     }
 }
 
+/// The `user_data` identifying the rigid-body that anchors joints to the world. It is out of the
+/// range of the 32-bit values set by `rbSetUserData`, so it can't match a user-defined value.
+const WORLD_ANCHOR_USER_DATA: u128 = 1 << 127;
+
+/// The velocity caps of a rigid-body, enforced by `RawRigidBodySet::clamp_velocities`.
+#[derive(Copy, Clone, Debug, Serialize, Deserialize)]
+pub(crate) struct VelocityLimits {
+    pub max_linvel: Real,
+    pub max_angvel: Real,
+}
+
+impl Default for VelocityLimits {
+    fn default() -> Self {
+        Self {
+            max_linvel: Real::INFINITY,
+            max_angvel: Real::INFINITY,
+        }
+    }
+}
+
 #[wasm_bindgen]
-pub struct RawRigidBodySet(pub(crate) RigidBodySet);
+pub struct RawRigidBodySet(
+    pub(crate) RigidBodySet,
+    pub(crate) HashMap<RigidBodyHandle, VelocityLimits>,
+);
 
 impl RawRigidBodySet {
     pub(crate) fn map<T>(&self, handle: FlatHandle, f: impl FnOnce(&RigidBody) -> T) -> T {
-        let body = self.0.get(utils::body_handle(handle)).expect(
-            "Invalid RigidBody reference. It may have been removed from the physics World.",
-        );
+        let body = self
+            .0
+            .get(utils::body_handle(handle))
+            .unwrap_or_else(|| utils::throw_invalid_handle("RigidBody"));
         f(body)
     }
 
@@ -51,18 +79,201 @@ impl RawRigidBodySet {
         handle: FlatHandle,
         f: impl FnOnce(&mut RigidBody) -> T,
     ) -> T {
-        let body = self.0.get_mut(utils::body_handle(handle)).expect(
-            "Invalid RigidBody reference. It may have been removed from the physics World.",
-        );
+        let body = self
+            .0
+            .get_mut(utils::body_handle(handle))
+            .unwrap_or_else(|| utils::throw_invalid_handle("RigidBody"));
         f(body)
     }
+
+    /// Clamps the velocities of the rigid-bodies that have velocity caps.
+    ///
+    /// This is called before and after each simulation step (or substep), so that neither the
+    /// velocities integrated by a step nor those read back after it exceed the caps set with
+    /// `rbSetMaxLinvel` and `rbSetMaxAngvel`.
+    pub(crate) fn clamp_velocities(&mut self) {
+        for (handle, limits) in self.1.iter() {
+            let body = match self.0.get_mut(*handle) {
+                Some(body) if body.is_dynamic() && !body.is_sleeping() => body,
+                _ => continue,
+            };
+
+            let linvel = *body.linvel();
+            let linvel_norm = linvel.norm();
+            if linvel_norm > limits.max_linvel {
+                body.set_linvel(linvel * (limits.max_linvel / linvel_norm), false);
+            }
+
+            #[cfg(feature = "dim2")]
+            {
+                let angvel = body.angvel();
+                if angvel.abs() > limits.max_angvel {
+                    body.set_angvel(limits.max_angvel.copysign(angvel), false);
+                }
+            }
+
+            #[cfg(feature = "dim3")]
+            {
+                let angvel = *body.angvel();
+                let angvel_norm = angvel.norm();
+                if angvel_norm > limits.max_angvel {
+                    body.set_angvel(angvel * (limits.max_angvel / angvel_norm), false);
+                }
+            }
+        }
+    }
+
+    /// The velocity caps of this set, sorted by rigid-body handle so that they are serialized
+    /// deterministically.
+    pub(crate) fn sorted_velocity_limits(&self) -> Vec<(RigidBodyHandle, VelocityLimits)> {
+        let mut limits: Vec<_> = self.1.iter().map(|(h, l)| (*h, *l)).collect();
+        limits.sort_by_key(|(h, _)| h.into_raw_parts());
+        limits
+    }
+
+    /// The current and next positions of the position-based kinematic rigid-bodies that are about
+    /// to move during the next step.
+    pub(crate) fn kinematic_motions(
+        &self,
+    ) -> Vec<(RigidBodyHandle, Isometry<Real>, Isometry<Real>)> {
+        self.0
+            .iter()
+            .filter(|(_, body)| body.body_type() == RigidBodyType::KinematicPositionBased)
+            .map(|(handle, body)| (handle, *body.position(), *body.next_position()))
+            .filter(|(_, start, end)| start != end)
+            .collect()
+    }
+
+    /// Sets the next position of each rigid-body of `motions` (computed by `kinematic_motions`)
+    /// to its position interpolated at `t`, from 0 (the start of the motion) to 1 (its end).
+    ///
+    /// This spreads the motion of the kinematic rigid-bodies over all the substeps of a step,
+    /// instead of moving them all the way during the first substep.
+    pub(crate) fn interpolate_kinematic_motions(
+        &mut self,
+        motions: &[(RigidBodyHandle, Isometry<Real>, Isometry<Real>)],
+        t: Real,
+    ) {
+        for (handle, start, end) in motions {
+            if let Some(body) = self.0.get_mut(*handle) {
+                #[cfg(feature = "dim2")]
+                let pos = start.lerp_slerp(end, t);
+                #[cfg(feature = "dim3")]
+                let pos = start
+                    .try_lerp_slerp(end, t, Real::EPSILON)
+                    .unwrap_or_else(|| {
+                        // The rotation path is ambiguous (half-turn): only interpolate the
+                        // translation.
+                        let rot = if t < 1.0 {
+                            start.rotation
+                        } else {
+                            end.rotation
+                        };
+                        let tra = start.translation.vector.lerp(&end.translation.vector, t);
+                        Isometry::from_parts(tra.into(), rot)
+                    });
+                body.set_next_kinematic_position(pos);
+            }
+        }
+    }
+
+    // Writes the translation followed by the rotation of `body` into `out`.
+    fn write_position(body: &RigidBody, out: &mut [f32]) {
+        let pos = body.position();
+        #[cfg(feature = "dim2")]
+        {
+            out[0] = pos.translation.x;
+            out[1] = pos.translation.y;
+            out[2] = pos.rotation.angle();
+        }
+        #[cfg(feature = "dim3")]
+        {
+            out[0] = pos.translation.x;
+            out[1] = pos.translation.y;
+            out[2] = pos.translation.z;
+            out[3] = pos.rotation.i;
+            out[4] = pos.rotation.j;
+            out[5] = pos.rotation.k;
+            out[6] = pos.rotation.w;
+        }
+    }
+
+    fn write_positions<'a>(
+        bodies: impl Iterator<Item = (RigidBodyHandle, &'a RigidBody)>,
+        handles: &mut [FlatHandle],
+        positions: &mut [f32],
+    ) -> usize {
+        let stride = Self::positionStride();
+        let mut count = 0;
+
+        for (handle, body) in bodies {
+            if count >= handles.len() || (count + 1) * stride > positions.len() {
+                break;
+            }
+
+            handles[count] = utils::flat_handle(handle.0);
+            Self::write_position(body, &mut positions[count * stride..(count + 1) * stride]);
+            count += 1;
+        }
+
+        count
+    }
+
+    fn set_translations(
+        &mut self,
+        handles: &[FlatHandle],
+        translations: &[f32],
+        mut f: impl FnMut(&mut RigidBody, Vector<f32>),
+    ) -> usize {
+        let mut count = 0;
+
+        for (handle, tra) in handles.iter().zip(translations.chunks_exact(DIM)) {
+            if let Some(body) = self.0.get_mut(utils::body_handle(*handle)) {
+                f(body, Vector::from_column_slice(tra));
+                count += 1;
+            }
+        }
+
+        count
+    }
 }
 
 #[wasm_bindgen]
 impl RawRigidBodySet {
     #[wasm_bindgen(constructor)]
     pub fn new() -> Self {
-        RawRigidBodySet(RigidBodySet::new())
+        RawRigidBodySet(RigidBodySet::new(), HashMap::new())
+    }
+
+    /// Removes all the rigid-bodies from this set.
+    ///
+    /// All their handles become invalid. Attached colliders and joints are not removed from their
+    /// own sets, so this should be done together with clearing the rest of the world.
+    pub fn clear(&mut self) {
+        self.0 = RigidBodySet::new();
+        self.1.clear();
+    }
+
+    /// Inserts a copy of every rigid-body of `other` into this set.
+    ///
+    /// The copies are inserted without colliders: use `RawColliderSet::mergeFrom` with the
+    /// returned table to copy these too. Returns a handle remap table made of one
+    /// `[oldHandle, newHandle]` pair per rigid-body of `other`.
+    pub fn mergeFrom(&mut self, other: &RawRigidBodySet) -> Vec<FlatHandle> {
+        let mut remap = Vec::with_capacity(other.0.len() * 2);
+
+        for (handle, body) in other.0.iter() {
+            let new_handle = self.0.insert(body.clone());
+
+            if let Some(limits) = other.1.get(&handle) {
+                self.1.insert(new_handle, *limits);
+            }
+
+            remap.push(utils::flat_handle(handle.0));
+            remap.push(utils::flat_handle(new_handle.0));
+        }
+
+        remap
     }
 
     #[cfg(feature = "dim3")]
@@ -182,6 +393,10 @@ impl RawRigidBodySet {
         utils::flat_handle(self.0.insert(rigid_body.build()).0)
     }
 
+    /// Removes a rigid-body from this set, along with all the colliders attached to it and all
+    /// the impulse and multibody joints attached to it.
+    ///
+    /// The removed handles become invalid: `contains` will return `false` for them.
     pub fn remove(
         &mut self,
         handle: FlatHandle,
@@ -191,6 +406,7 @@ impl RawRigidBodySet {
         articulations: &mut RawMultibodyJointSet,
     ) {
         let handle = utils::body_handle(handle);
+        self.1.remove(&handle);
         self.0.remove(
             handle,
             &mut islands.0,
@@ -211,10 +427,27 @@ impl RawRigidBodySet {
         self.0.get(utils::body_handle(handle)).is_some()
     }
 
+    /// Marks a rigid-body as the one anchoring joints to the world.
+    ///
+    /// The mark is part of the rigid-body's state, so it is kept by snapshots and copies, and the
+    /// anchor can be found again with `worldAnchor`. Calling `rbSetUserData` on this rigid-body
+    /// removes the mark.
+    pub fn markWorldAnchor(&mut self, handle: FlatHandle) {
+        self.map_mut(handle, |rb| rb.user_data = WORLD_ANCHOR_USER_DATA)
+    }
+
+    /// The handle of the rigid-body marked with `markWorldAnchor`, if any.
+    pub fn worldAnchor(&self) -> Option<FlatHandle> {
+        self.0
+            .iter()
+            .find(|(_, rb)| rb.user_data == WORLD_ANCHOR_USER_DATA)
+            .map(|(handle, _)| utils::flat_handle(handle.0))
+    }
+
     /// Applies the given JavaScript function to the integer handle of each rigid-body managed by this set.
     ///
     /// # Parameters
-    /// - `f(handle)`: the function to apply to the integer handle of each rigid-body managed by this set. Called as `f(collider)`.
+    /// - `f(handle)`: the function to apply to the integer handle of each rigid-body managed by this set. Called as `f(handle)`.
     pub fn forEachRigidBodyHandle(&self, f: &js_sys::Function) {
         let this = JsValue::null();
         for (handle, _) in self.0.iter() {
@@ -226,4 +459,84 @@ impl RawRigidBodySet {
         self.0
             .propagate_modified_body_positions_to_colliders(&mut colliders.0);
     }
+
+    /// The number of floats written per rigid-body by `writePositions` and `writeActivePositions`.
+    ///
+    /// In 2D, each rigid-body position is written as its translation `(x, y)` followed by its
+    /// rotation angle. In 3D, it is written as its translation `(x, y, z)` followed by its rotation
+    /// quaternion `(x, y, z, w)`.
+    pub fn positionStride() -> usize {
+        if cfg!(feature = "dim2") {
+            3
+        } else {
+            7
+        }
+    }
+
+    /// Writes the handle and position of every rigid-body of this set into the given buffers.
+    ///
+    /// The position of the rigid-body with handle `handles[i]` is written at
+    /// `positions[i * stride..(i + 1) * stride]` where `stride` is `positionStride()`. Writing
+    /// stops as soon as either buffer is full.
+    ///
+    /// Returns the number of rigid-bodies written.
+    pub fn writePositions(&self, handles: &mut [FlatHandle], positions: &mut [f32]) -> usize {
+        Self::write_positions(self.0.iter(), handles, positions)
+    }
+
+    /// Writes the handle and position of every active (non-sleeping) dynamic or kinematic
+    /// rigid-body into the given buffers.
+    ///
+    /// The buffers are laid out the same way as for `writePositions`.
+    ///
+    /// Returns the number of rigid-bodies written.
+    pub fn writeActivePositions(
+        &self,
+        islands: &RawIslandManager,
+        handles: &mut [FlatHandle],
+        positions: &mut [f32],
+    ) -> usize {
+        let active = islands
+            .0
+            .active_dynamic_bodies()
+            .iter()
+            .chain(islands.0.active_kinematic_bodies().iter())
+            .filter_map(|h| self.0.get(*h).map(|body| (*h, body)));
+        Self::write_positions(active, handles, positions)
+    }
+
+    /// Sets the translation of many rigid-bodies with a single call.
+    ///
+    /// The translation of the rigid-body with handle `handles[i]` is read from
+    /// `translations[i * dim..(i + 1) * dim]` where `dim` is 2 in 2D and 3 in 3D. Handles that
+    /// don't match any rigid-body of this set are skipped, as are handles without a complete
+    /// translation.
+    ///
+    /// Returns the number of rigid-bodies actually modified.
+    pub fn setTranslations(
+        &mut self,
+        handles: &[FlatHandle],
+        translations: &[f32],
+        wakeUp: bool,
+    ) -> usize {
+        self.set_translations(handles, translations, |rb, tra| {
+            rb.set_translation(tra, wakeUp)
+        })
+    }
+
+    /// Sets the next kinematic translation of many kinematic rigid-bodies with a single call.
+    ///
+    /// The buffers are laid out the same way as for `setTranslations`. This has no effect on
+    /// rigid-bodies that are not kinematic.
+    ///
+    /// Returns the number of rigid-bodies found in this set.
+    pub fn setNextKinematicTranslations(
+        &mut self,
+        handles: &[FlatHandle],
+        translations: &[f32],
+    ) -> usize {
+        self.set_translations(handles, translations, |rb, tra| {
+            rb.set_next_kinematic_translation(tra)
+        })
+    }
 }
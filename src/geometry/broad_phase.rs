@@ -10,4 +10,9 @@ impl RawBroadPhase {
     pub fn new() -> Self {
         RawBroadPhase(BroadPhase::new())
     }
+
+    /// Removes all the collider proxies and pairs tracked by this broad-phase.
+    pub fn clear(&mut self) {
+        self.0 = BroadPhase::new();
+    }
 }
@@ -1,17 +1,40 @@
+use crate::dynamics::RawRigidBodySet;
 use crate::geometry::shape::SharedShapeUtility;
 use crate::geometry::{
-    RawColliderSet, RawPointProjection, RawRayIntersection, RawShape, RawShapeColliderTOI,
-    RawShapeContact, RawShapeTOI, RawShapeType,
+    RawClosestPoints, RawColliderSet, RawPointColliderProjection, RawPointProjection,
+    RawRayIntersection, RawShape, RawShapeColliderTOI, RawShapeContact, RawShapeTOI, RawShapeType,
 };
 use crate::math::{RawRotation, RawVector};
 use crate::utils::{self, FlatHandle};
 use rapier::dynamics::MassProperties;
-use rapier::geometry::{ActiveCollisionTypes, ShapeType};
+use rapier::geometry::{ActiveCollisionTypes, Collider, HeightField, ShapeType, SharedShape};
 use rapier::math::{Isometry, Point, Real, Vector};
-use rapier::parry::query;
+use rapier::parry::query::{self, PointQuery};
 use rapier::pipeline::{ActiveEvents, ActiveHooks};
 use wasm_bindgen::prelude::*;
 
+// The world-space position of a collider. If it is attached to a rigid-body, it is computed from
+// the current position of its parent since the collider's own position is only updated by a step.
+fn world_position(co: &Collider, bodies: &RawRigidBodySet) -> Isometry<Real> {
+    match (
+        co.parent().and_then(|h| bodies.0.get(h)),
+        co.position_wrt_parent(),
+    ) {
+        (Some(parent), Some(pos_wrt_parent)) => parent.position() * pos_wrt_parent,
+        _ => *co.position(),
+    }
+}
+
+// Sets the world-space position of a collider. If it is attached to a rigid-body, its position
+// relative to its parent is solved for so it isn't overwritten by the parent's at the next step.
+fn set_world_position(co: &mut Collider, bodies: &RawRigidBodySet, pos: Isometry<Real>) {
+    if let Some(parent) = co.parent().and_then(|h| bodies.0.get(h)) {
+        co.set_position_wrt_parent(parent.position().inverse() * pos);
+    }
+
+    co.set_position(pos);
+}
+
 #[wasm_bindgen]
 impl RawColliderSet {
     /// The world-space translation of this collider.
@@ -24,32 +47,88 @@ impl RawColliderSet {
         self.map(handle, |co| co.position().rotation.into())
     }
 
+    /// The translation of this collider relative to its parent rigid-body.
+    ///
+    /// Returns `undefined` if this collider isn't attached to a rigid-body.
+    pub fn coTranslationWrtParent(&self, handle: FlatHandle) -> Option<RawVector> {
+        self.map(handle, |co| {
+            co.position_wrt_parent()
+                .map(|pos| pos.translation.vector.into())
+        })
+    }
+
+    /// The orientation of this collider relative to its parent rigid-body.
+    ///
+    /// Returns `undefined` if this collider isn't attached to a rigid-body.
+    pub fn coRotationWrtParent(&self, handle: FlatHandle) -> Option<RawRotation> {
+        self.map(handle, |co| {
+            co.position_wrt_parent().map(|pos| pos.rotation.into())
+        })
+    }
+
+    /// The lower bound of the world-space AABB of this collider.
+    ///
+    /// It is computed from the collider's current position, i.e., its position at the end of
+    /// the last step unless it was modified since.
+    pub fn coAabbMin(&self, handle: FlatHandle) -> RawVector {
+        self.map(handle, |co| co.compute_aabb().mins.into())
+    }
+
+    /// The upper bound of the world-space AABB of this collider.
+    ///
+    /// It is computed from the collider's current position, i.e., its position at the end of
+    /// the last step unless it was modified since.
+    pub fn coAabbMax(&self, handle: FlatHandle) -> RawVector {
+        self.map(handle, |co| co.compute_aabb().maxs.into())
+    }
+
     /// Sets the translation of this collider.
     ///
+    /// If this collider is attached to a rigid-body, its position relative to that rigid-body is
+    /// updated accordingly, so it doesn't snap back to its previous offset at the next step.
+    ///
     /// # Parameters
     /// - `x`: the world-space position of the collider along the `x` axis.
     /// - `y`: the world-space position of the collider along the `y` axis.
     /// - `z`: the world-space position of the collider along the `z` axis.
-    /// - `wakeUp`: forces the collider to wake-up so it is properly affected by forces if it
-    /// wasn't moving before modifying its position.
+    /// - `bodies`: the set containing the parent rigid-body of this collider, if any.
     #[cfg(feature = "dim3")]
-    pub fn coSetTranslation(&mut self, handle: FlatHandle, x: f32, y: f32, z: f32) {
+    pub fn coSetTranslation(
+        &mut self,
+        handle: FlatHandle,
+        x: f32,
+        y: f32,
+        z: f32,
+        bodies: &RawRigidBodySet,
+    ) {
         self.map_mut(handle, |co| {
-            co.set_translation(na::Vector3::new(x, y, z));
+            let mut pos = world_position(co, bodies);
+            pos.translation.vector = na::Vector3::new(x, y, z);
+            set_world_position(co, bodies, pos);
         })
     }
 
     /// Sets the translation of this collider.
     ///
+    /// If this collider is attached to a rigid-body, its position relative to that rigid-body is
+    /// updated accordingly, so it doesn't snap back to its previous offset at the next step.
+    ///
     /// # Parameters
     /// - `x`: the world-space position of the collider along the `x` axis.
     /// - `y`: the world-space position of the collider along the `y` axis.
-    /// - `wakeUp`: forces the collider to wake-up so it is properly affected by forces if it
-    /// wasn't moving before modifying its position.
+    /// - `bodies`: the set containing the parent rigid-body of this collider, if any.
     #[cfg(feature = "dim2")]
-    pub fn coSetTranslation(&mut self, handle: FlatHandle, x: f32, y: f32) {
+    pub fn coSetTranslation(
+        &mut self,
+        handle: FlatHandle,
+        x: f32,
+        y: f32,
+        bodies: &RawRigidBodySet,
+    ) {
         self.map_mut(handle, |co| {
-            co.set_translation(na::Vector2::new(x, y));
+            let mut pos = world_position(co, bodies);
+            pos.translation.vector = na::Vector2::new(x, y);
+            set_world_position(co, bodies, pos);
         })
     }
 
@@ -69,6 +148,9 @@ impl RawColliderSet {
 
     /// Sets the rotation quaternion of this collider.
     ///
+    /// If this collider is attached to a rigid-body, its position relative to that rigid-body is
+    /// updated accordingly, so it doesn't snap back to its previous offset at the next step.
+    ///
     /// This does nothing if a zero quaternion is provided.
     ///
     /// # Parameters
@@ -76,24 +158,41 @@ impl RawColliderSet {
     /// - `y`: the second vector component of the quaternion.
     /// - `z`: the third vector component of the quaternion.
     /// - `w`: the scalar component of the quaternion.
-    /// - `wakeUp`: forces the collider to wake-up so it is properly affected by forces if it
-    /// wasn't moving before modifying its position.
+    /// - `bodies`: the set containing the parent rigid-body of this collider, if any.
     #[cfg(feature = "dim3")]
-    pub fn coSetRotation(&mut self, handle: FlatHandle, x: f32, y: f32, z: f32, w: f32) {
+    pub fn coSetRotation(
+        &mut self,
+        handle: FlatHandle,
+        x: f32,
+        y: f32,
+        z: f32,
+        w: f32,
+        bodies: &RawRigidBodySet,
+    ) {
         if let Some(q) = na::Unit::try_new(na::Quaternion::new(w, x, y, z), 0.0) {
-            self.map_mut(handle, |co| co.set_rotation(q))
+            self.map_mut(handle, |co| {
+                let mut pos = world_position(co, bodies);
+                pos.rotation = q;
+                set_world_position(co, bodies, pos);
+            })
         }
     }
 
     /// Sets the rotation angle of this collider.
     ///
+    /// If this collider is attached to a rigid-body, its position relative to that rigid-body is
+    /// updated accordingly, so it doesn't snap back to its previous offset at the next step.
+    ///
     /// # Parameters
     /// - `angle`: the rotation angle, in radians.
-    /// - `wakeUp`: forces the collider to wake-up so it is properly affected by forces if it
-    /// wasn't moving before modifying its position.
+    /// - `bodies`: the set containing the parent rigid-body of this collider, if any.
     #[cfg(feature = "dim2")]
-    pub fn coSetRotation(&mut self, handle: FlatHandle, angle: f32) {
-        self.map_mut(handle, |co| co.set_rotation(na::UnitComplex::new(angle)))
+    pub fn coSetRotation(&mut self, handle: FlatHandle, angle: f32, bodies: &RawRigidBodySet) {
+        self.map_mut(handle, |co| {
+            let mut pos = world_position(co, bodies);
+            pos.rotation = na::UnitComplex::new(angle);
+            set_world_position(co, bodies, pos);
+        })
     }
 
     #[cfg(feature = "dim3")]
@@ -273,6 +372,9 @@ impl RawColliderSet {
     }
 
     /// The radius of the round edges of this collider.
+    ///
+    /// Returns `None` if the collider's shape isn't a rounded shape. The shape is inflated
+    /// by this radius, so the rendered geometry should be inflated by the same amount.
     pub fn coRoundRadius(&self, handle: FlatHandle) -> Option<f32> {
         self.map(handle, |co| match co.shape().shape_type() {
             ShapeType::RoundCuboid => co.shape().as_round_cuboid().map(|b| b.border_radius),
@@ -438,15 +540,164 @@ impl RawColliderSet {
         })
     }
 
+    /// The height of the `i`-th point of this heightfield, if it is one.
+    ///
+    /// Returns `None` if this isn't a heightfield or if `i` is out of bounds.
+    #[cfg(feature = "dim2")]
+    pub fn coHeightfieldHeight(&self, handle: FlatHandle, i: usize) -> Option<f32> {
+        self.map(handle, |co| {
+            co.shape()
+                .as_heightfield()
+                .and_then(|h| h.heights().get(i).copied())
+        })
+    }
+
+    /// The height at the `i`-th row and `j`-th column of this heightfield, if it is one.
+    ///
+    /// Returns `None` if this isn't a heightfield or if `(i, j)` is out of bounds.
+    #[cfg(feature = "dim3")]
+    pub fn coHeightfieldHeight(&self, handle: FlatHandle, i: usize, j: usize) -> Option<f32> {
+        self.map(handle, |co| {
+            co.shape()
+                .as_heightfield()
+                .and_then(|h| h.heights().get((i, j)).copied())
+        })
+    }
+
+    /// Sets the height of the `i`-th point of this heightfield.
+    ///
+    /// The heightfield is rebuilt with the same scale and removed segments, and the collider's
+    /// broad-phase proxy is updated at the next step. Returns `false` if this isn't a heightfield
+    /// or if `i` is out of bounds.
+    ///
+    /// Rebuilding the heightfield costs as much as creating it, whatever the number of heights
+    /// changed: use `coHeightfieldSetHeights` to change several heights at once.
+    #[cfg(feature = "dim2")]
+    pub fn coHeightfieldSetHeight(&mut self, handle: FlatHandle, i: usize, height: f32) -> bool {
+        self.coHeightfieldSetHeights(handle, i, vec![height])
+    }
+
+    /// Sets the heights of consecutive points of this heightfield, starting at the `i`-th one.
+    ///
+    /// The heightfield is rebuilt once for the whole batch, with the same scale and removed
+    /// segments, and the collider's broad-phase proxy is updated at the next step. Returns
+    /// `false`, without changing anything, if this isn't a heightfield or if a point is out of
+    /// bounds.
+    ///
+    /// Rebuilding the heightfield costs as much as creating it, i.e., it is linear in its total
+    /// number of points rather than in the number of heights changed.
+    #[cfg(feature = "dim2")]
+    pub fn coHeightfieldSetHeights(
+        &mut self,
+        handle: FlatHandle,
+        i: usize,
+        heights: Vec<f32>,
+    ) -> bool {
+        self.map_mut(handle, |co| {
+            let heightfield = match co.shape().as_heightfield() {
+                Some(h) if i + heights.len() <= h.heights().len() => h,
+                _ => return false,
+            };
+
+            let mut new_heights = heightfield.heights().clone();
+            new_heights
+                .rows_mut(i, heights.len())
+                .copy_from_slice(&heights);
+            let mut new_heightfield = HeightField::new(new_heights, *heightfield.scale());
+            for k in 0..heightfield.num_cells() {
+                new_heightfield.set_segment_removed(k, heightfield.is_segment_removed(k));
+            }
+
+            co.set_shape(SharedShape::new(new_heightfield));
+            true
+        })
+    }
+
+    /// Sets the height at the `i`-th row and `j`-th column of this heightfield.
+    ///
+    /// The heightfield is rebuilt with the same scale and cell statuses, and the collider's
+    /// broad-phase proxy is updated at the next step. Returns `false` if this isn't a heightfield
+    /// or if `(i, j)` is out of bounds.
+    ///
+    /// Rebuilding the heightfield costs as much as creating it, whatever the number of heights
+    /// changed: use `coHeightfieldSetHeights` to change several heights at once.
+    #[cfg(feature = "dim3")]
+    pub fn coHeightfieldSetHeight(
+        &mut self,
+        handle: FlatHandle,
+        i: usize,
+        j: usize,
+        height: f32,
+    ) -> bool {
+        self.coHeightfieldSetHeights(handle, i, j, 1, vec![height])
+    }
+
+    /// Sets the heights of a rectangular patch of this heightfield.
+    ///
+    /// The patch starts at the `i`-th row and `j`-th column, and spans `nrows` rows. The `heights`
+    /// are given in column-major order, like when creating the heightfield, so the patch spans
+    /// `heights.length / nrows` columns. The heightfield is rebuilt once for the whole patch,
+    /// with the same scale and cell statuses, and the collider's broad-phase proxy is updated at
+    /// the next step. Returns `false`, without changing anything, if this isn't a heightfield or
+    /// if the patch is out of bounds.
+    ///
+    /// Rebuilding the heightfield costs as much as creating it, i.e., it is linear in its total
+    /// number of heights rather than in the size of the patch.
+    #[cfg(feature = "dim3")]
+    pub fn coHeightfieldSetHeights(
+        &mut self,
+        handle: FlatHandle,
+        i: usize,
+        j: usize,
+        nrows: usize,
+        heights: Vec<f32>,
+    ) -> bool {
+        if nrows == 0 || heights.len() % nrows != 0 {
+            return false;
+        }
+        let ncols = heights.len() / nrows;
+
+        self.map_mut(handle, |co| {
+            let heightfield = match co.shape().as_heightfield() {
+                Some(h) => h,
+                None => return false,
+            };
+            let (hrows, hcols) = heightfield.heights().shape();
+            if i + nrows > hrows || j + ncols > hcols {
+                return false;
+            }
+
+            let mut new_heights = heightfield.heights().clone();
+            new_heights
+                .view_mut((i, j), (nrows, ncols))
+                .copy_from_slice(&heights);
+            let mut new_heightfield = HeightField::new(new_heights, *heightfield.scale());
+            let statuses = heightfield.cells_statuses();
+            for ci in 0..statuses.nrows() {
+                for cj in 0..statuses.ncols() {
+                    new_heightfield.set_cell_status(ci, cj, statuses[(ci, cj)]);
+                }
+            }
+
+            co.set_shape(SharedShape::new(new_heightfield));
+            true
+        })
+    }
+
     /// The unique integer identifier of the collider this collider is attached to.
     pub fn coParent(&self, handle: FlatHandle) -> Option<FlatHandle> {
         self.map(handle, |co| co.parent().map(|p| utils::flat_handle(p.0)))
     }
 
+    /// Enables or disables this collider.
+    ///
+    /// A disabled collider stays attached to its parent rigid-body but is ignored by collision
+    /// detection and scene queries. Re-enabling it takes effect at the next step.
     pub fn coSetEnabled(&mut self, handle: FlatHandle, enabled: bool) {
         self.map_mut(handle, |co| co.set_enabled(enabled))
     }
 
+    /// Is this collider enabled?
     pub fn coIsEnabled(&self, handle: FlatHandle) -> bool {
         self.map(handle, |co| co.is_enabled())
     }
@@ -461,11 +712,16 @@ impl RawColliderSet {
     }
 
     /// The density of this collider.
+    ///
+    /// If the mass of this collider was set explicitly, this is the density derived from that
+    /// mass and the collider's volume.
     pub fn coDensity(&self, handle: FlatHandle) -> f32 {
         self.map(handle, |co| co.density())
     }
 
-    /// The mass of this collider.
+    /// The mass this collider contributes to the rigid-body it is attached to.
+    ///
+    /// This is zero for colliders with a density of zero.
     pub fn coMass(&self, handle: FlatHandle) -> f32 {
         self.map(handle, |co| co.mass())
     }
@@ -556,7 +812,7 @@ impl RawColliderSet {
         let co2 = self
             .0
             .get(handle2)
-            .expect("Invalid Collider reference. It may have been removed from the physics World.");
+            .unwrap_or_else(|| utils::throw_invalid_handle("Collider"));
 
         self.map(handle, |co| {
             query::time_of_impact(
@@ -619,7 +875,7 @@ impl RawColliderSet {
         let co2 = self
             .0
             .get(utils::collider_handle(collider2handle))
-            .expect("Invalid Collider reference. It may have been removed from the physics World.");
+            .unwrap_or_else(|| utils::throw_invalid_handle("Collider"));
 
         self.map(handle, |co| {
             query::contact(
@@ -635,6 +891,37 @@ impl RawColliderSet {
         })
     }
 
+    /// Computes the closest points between this collider and another collider.
+    ///
+    /// # Parameters
+    /// - `maxDist`: if the colliders are separated by a distance greater than this value, the
+    ///   closest points aren't computed and the result has the `Disjoint` status.
+    ///
+    /// Returns `undefined` if the closest points aren't supported for this pair of shapes.
+    pub fn coClosestPoints(
+        &self,
+        handle: FlatHandle,
+        collider2handle: FlatHandle,
+        maxDist: f32,
+    ) -> Option<RawClosestPoints> {
+        let co2 = self
+            .0
+            .get(utils::collider_handle(collider2handle))
+            .unwrap_or_else(|| utils::throw_invalid_handle("Collider"));
+
+        self.map(handle, |co| {
+            query::closest_points(
+                co.position(),
+                co.shape(),
+                co2.position(),
+                co2.shape(),
+                maxDist,
+            )
+            .ok()
+            .map(|closest_points| RawClosestPoints { closest_points })
+        })
+    }
+
     pub fn coProjectPoint(
         &self,
         handle: FlatHandle,
@@ -647,6 +934,29 @@ impl RawColliderSet {
         })
     }
 
+    /// Projects a point on the boundary of this collider, and returns the geometric feature it
+    /// was projected on.
+    ///
+    /// The feature id refers to a vertex, an edge (in 3D), or a face of the shape, using the
+    /// same indexing as the shape itself, e.g., the triangle index for the faces of a triangle
+    /// mesh.
+    pub fn coProjectPointAndGetFeature(
+        &self,
+        handle: FlatHandle,
+        point: &RawVector,
+    ) -> RawPointColliderProjection {
+        self.map(handle, |co| {
+            let (proj, feature) = co
+                .shape()
+                .project_point_and_get_feature(co.position(), &point.0.into());
+            RawPointColliderProjection {
+                handle: utils::collider_handle(handle),
+                proj,
+                feature,
+            }
+        })
+    }
+
     pub fn coIntersectsRay(
         &self,
         handle: FlatHandle,
@@ -714,6 +1024,7 @@ impl RawColliderSet {
         self.map_mut(handle, |co| co.set_friction(friction))
     }
 
+    /// The rule used to combine the friction coefficient of this collider with another one.
     pub fn coFrictionCombineRule(&self, handle: FlatHandle) -> u32 {
         self.map(handle, |co| co.friction_combine_rule() as u32)
     }
@@ -723,6 +1034,7 @@ impl RawColliderSet {
         self.map_mut(handle, |co| co.set_friction_combine_rule(rule))
     }
 
+    /// The rule used to combine the restitution coefficient of this collider with another one.
     pub fn coRestitutionCombineRule(&self, handle: FlatHandle) -> u32 {
         self.map(handle, |co| co.restitution_combine_rule() as u32)
     }
@@ -742,33 +1054,70 @@ impl RawColliderSet {
         self.map_mut(handle, |co| co.set_solver_groups(groups))
     }
 
+    /// Sets the physics hooks enabled for this collider.
+    ///
+    /// The `hooks` bits must match the `ActiveHooks` flags of Rapier. Unknown bits are ignored.
+    /// This can be changed at any time and takes effect at the next step.
     pub fn coSetActiveHooks(&mut self, handle: FlatHandle, hooks: u32) {
-        let hooks = ActiveHooks::from_bits(hooks).unwrap_or(ActiveHooks::empty());
+        let hooks = ActiveHooks::from_bits_truncate(hooks);
         self.map_mut(handle, |co| co.set_active_hooks(hooks));
     }
 
+    /// Sets the events enabled for this collider.
+    ///
+    /// The `events` bits must match the `ActiveEvents` flags of Rapier. Unknown bits are ignored.
+    /// This can be changed at any time and takes effect at the next step.
     pub fn coSetActiveEvents(&mut self, handle: FlatHandle, events: u32) {
-        let events = ActiveEvents::from_bits(events).unwrap_or(ActiveEvents::empty());
+        let events = ActiveEvents::from_bits_truncate(events);
         self.map_mut(handle, |co| co.set_active_events(events))
     }
 
+    /// Sets the collision types enabled for this collider.
+    ///
+    /// The `types` bits must match the `ActiveCollisionTypes` flags of Rapier. By default, only
+    /// pairs involving at least one dynamic rigid-body are active, so a collider attached to a
+    /// kinematic or fixed rigid-body needs `KINEMATIC_FIXED` or `KINEMATIC_KINEMATIC` to detect
+    /// collisions with other non-dynamic colliders. Unknown bits are ignored.
     pub fn coSetActiveCollisionTypes(&mut self, handle: FlatHandle, types: u16) {
-        let types = ActiveCollisionTypes::from_bits(types).unwrap_or(ActiveCollisionTypes::empty());
+        let types = ActiveCollisionTypes::from_bits_truncate(types);
         self.map_mut(handle, |co| co.set_active_collision_types(types));
     }
 
+    /// Replaces the shape of this collider, keeping all its other properties.
+    ///
+    /// The mass properties of the parent rigid-body are updated at the next step, or
+    /// immediately with `rbRecomputeMassPropertiesFromColliders`.
     pub fn coSetShape(&mut self, handle: FlatHandle, shape: &RawShape) {
         self.map_mut(handle, |co| co.set_shape(shape.0.clone()));
     }
 
+    /// Sets the total force magnitude beyond which a contact force event can be emitted.
     pub fn coSetContactForceEventThreshold(&mut self, handle: FlatHandle, threshold: f32) {
         self.map_mut(handle, |co| co.set_contact_force_event_threshold(threshold))
     }
 
+    /// Enables the contact force events of this collider, with the given force threshold.
+    ///
+    /// This adds the `CONTACT_FORCE_EVENTS` flag to the active events of this collider, keeping
+    /// the other flags, and sets its contact force event threshold in a single call.
+    pub fn coEnableContactForceEvents(&mut self, handle: FlatHandle, threshold: f32) {
+        self.map_mut(handle, |co| {
+            co.set_active_events(co.active_events() | ActiveEvents::CONTACT_FORCE_EVENTS);
+            co.set_contact_force_event_threshold(threshold);
+        })
+    }
+
+    /// Sets the uniform density of this collider.
+    ///
+    /// A density of zero makes the collider massless: it still generates contacts and
+    /// intersections, but doesn't contribute to the mass properties of its parent rigid-body.
     pub fn coSetDensity(&mut self, handle: FlatHandle, density: f32) {
         self.map_mut(handle, |co| co.set_density(density))
     }
 
+    /// Sets the mass of this collider.
+    ///
+    /// A mass of zero makes the collider massless, like a density of zero.
     pub fn coSetMass(&mut self, handle: FlatHandle, mass: f32) {
         self.map_mut(handle, |co| co.set_mass(mass))
     }
@@ -20,7 +20,7 @@ impl RawColliderSet {
         let collider = self
             .0
             .get(utils::collider_handle(handle))
-            .expect("Invalid Collider reference. It may have been removed from the physics World.");
+            .unwrap_or_else(|| utils::throw_invalid_handle("Collider"));
         f(collider)
     }
 
@@ -32,7 +32,7 @@ impl RawColliderSet {
         let collider = self
             .0
             .get_mut(utils::collider_handle(handle))
-            .expect("Invalid Collider reference. It may have been removed from the physics World.");
+            .unwrap_or_else(|| utils::throw_invalid_handle("Collider"));
         f(collider)
     }
 }
@@ -126,6 +126,14 @@ impl RawColliderSet {
         RawColliderSet(ColliderSet::new())
     }
 
+    /// Removes all the colliders from this set.
+    ///
+    /// All their handles become invalid. The broad-phase and narrow-phase still reference them
+    /// until they are cleared too.
+    pub fn clear(&mut self) {
+        self.0 = ColliderSet::new();
+    }
+
     pub fn len(&self) -> usize {
         self.0.len()
     }
@@ -245,6 +253,13 @@ impl RawColliderSet {
     }
 
     /// Removes a collider from this set and wake-up the rigid-body it is attached to.
+    ///
+    /// The parent rigid-body, if any, is kept and its mass properties are updated to account for
+    /// the remaining colliders at the next step. The removed handle becomes invalid.
+    ///
+    /// # Parameters
+    /// - `wakeUp`: if `true`, the parent rigid-body is woken up so its remaining colliders
+    ///   resettle.
     pub fn remove(
         &mut self,
         handle: FlatHandle,
@@ -256,6 +271,49 @@ impl RawColliderSet {
         self.0.remove(handle, &mut islands.0, &mut bodies.0, wakeUp);
     }
 
+    /// Inserts a copy of every collider of `other` into this set.
+    ///
+    /// # Parameters
+    /// - `bodies`: the set containing the copies of the parents of the colliders of `other`.
+    /// - `bodyRemap`: the handle remap table returned by `RawRigidBodySet::mergeFrom`. Colliders
+    ///   attached to a rigid-body missing from this table are inserted without a parent, at
+    ///   their current world-space position.
+    ///
+    /// Returns a handle remap table made of one `[oldHandle, newHandle]` pair per collider of
+    /// `other`.
+    pub fn mergeFrom(
+        &mut self,
+        other: &RawColliderSet,
+        bodies: &mut RawRigidBodySet,
+        bodyRemap: Vec<FlatHandle>,
+    ) -> Vec<FlatHandle> {
+        let body_remap = utils::body_remap(&bodyRemap);
+        let mut remap = Vec::with_capacity(other.0.len() * 2);
+
+        for (handle, collider) in other.0.iter() {
+            let mut collider = collider.clone();
+            let parent = collider
+                .parent()
+                .and_then(|parent| body_remap.get(&parent).copied());
+
+            let new_handle = match parent {
+                Some(parent) => {
+                    // The collider is inserted relative to its parent.
+                    if let Some(pos_wrt_parent) = collider.position_wrt_parent().copied() {
+                        collider.set_position(pos_wrt_parent);
+                    }
+                    self.0.insert_with_parent(collider, parent, &mut bodies.0)
+                }
+                None => self.0.insert(collider),
+            };
+
+            remap.push(utils::flat_handle(handle.0));
+            remap.push(utils::flat_handle(new_handle.0));
+        }
+
+        remap
+    }
+
     /// Checks if a collider with the given integer handle exists.
     pub fn isHandleValid(&self, handle: FlatHandle) -> bool {
         self.0.get(utils::collider_handle(handle)).is_some()
@@ -29,3 +29,54 @@ impl RawShapeContact {
         self.contact.normal2.into_inner().into()
     }
 }
+
+/// The status of a closest-points query.
+// NOTE: this MUST match the same enum on the TS side.
+#[wasm_bindgen]
+#[derive(Copy, Clone)]
+pub enum RawClosestPointsStatus {
+    Intersecting = 0,
+    WithinMargin = 1,
+    Disjoint = 2,
+}
+
+#[wasm_bindgen]
+pub struct RawClosestPoints {
+    pub(crate) closest_points: query::ClosestPoints,
+}
+
+#[wasm_bindgen]
+impl RawClosestPoints {
+    pub fn status(&self) -> RawClosestPointsStatus {
+        match self.closest_points {
+            query::ClosestPoints::Intersecting => RawClosestPointsStatus::Intersecting,
+            query::ClosestPoints::WithinMargin(..) => RawClosestPointsStatus::WithinMargin,
+            query::ClosestPoints::Disjoint => RawClosestPointsStatus::Disjoint,
+        }
+    }
+
+    /// The distance between the two closest points, or zero if the shapes are intersecting.
+    ///
+    /// Returns `undefined` if the shapes are separated by more than the maximum distance.
+    pub fn distance(&self) -> Option<f32> {
+        match self.closest_points {
+            query::ClosestPoints::Intersecting => Some(0.0),
+            query::ClosestPoints::WithinMargin(p1, p2) => Some(na::distance(&p1, &p2)),
+            query::ClosestPoints::Disjoint => None,
+        }
+    }
+
+    pub fn point1(&self) -> Option<RawVector> {
+        match self.closest_points {
+            query::ClosestPoints::WithinMargin(p1, _) => Some(p1.coords.into()),
+            _ => None,
+        }
+    }
+
+    pub fn point2(&self) -> Option<RawVector> {
+        match self.closest_points {
+            query::ClosestPoints::WithinMargin(_, p2) => Some(p2.coords.into()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use wasm_bindgen::prelude::*;
+
+/// Helpers to pack and unpack the 32-bit interaction groups used by colliders.
+///
+/// The memberships are stored in the 16 left-most bits, and the filter in the 16 right-most bits.
+#[wasm_bindgen]
+pub struct RawInteractionGroups;
+
+#[wasm_bindgen]
+impl RawInteractionGroups {
+    /// Packs the given memberships and filter into interaction groups.
+    pub fn new(memberships: u16, filter: u16) -> u32 {
+        ((memberships as u32) << 16) | filter as u32
+    }
+
+    /// The memberships of the given interaction groups.
+    pub fn memberships(groups: u32) -> u16 {
+        (groups >> 16) as u16
+    }
+
+    /// The filter of the given interaction groups.
+    pub fn filter(groups: u32) -> u16 {
+        (groups & 0x0000_ffff) as u16
+    }
+}
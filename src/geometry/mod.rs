@@ -5,6 +5,7 @@ pub use self::collider::*;
 pub use self::collider_set::*;
 pub use self::contact::*;
 pub use self::feature::*;
+pub use self::interaction_groups::*;
 pub use self::narrow_phase::*;
 pub use self::point::*;
 pub use self::ray::*;
@@ -16,6 +17,7 @@ mod collider;
 mod collider_set;
 mod contact;
 mod feature;
+mod interaction_groups;
 mod narrow_phase;
 mod point;
 mod ray;
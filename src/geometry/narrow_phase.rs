@@ -1,7 +1,8 @@
+use crate::dynamics::RawRigidBodySet;
 use crate::math::RawVector;
 use crate::utils::{self, FlatHandle};
 use rapier::geometry::{ContactManifold, ContactPair, NarrowPhase};
-use rapier::math::Real;
+use rapier::math::{Real, Vector};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -14,6 +15,11 @@ impl RawNarrowPhase {
         RawNarrowPhase(NarrowPhase::new())
     }
 
+    /// Removes all the contact and intersection pairs tracked by this narrow-phase.
+    pub fn clear(&mut self) {
+        self.0 = NarrowPhase::new();
+    }
+
     pub fn contacts_with(&self, handle1: FlatHandle, f: js_sys::Function) {
         let this = JsValue::null();
         let handle1 = utils::collider_handle(handle1);
@@ -36,6 +42,9 @@ impl RawNarrowPhase {
             .map(|p| RawContactPair(p as *const ContactPair))
     }
 
+    /// Calls `f` with the handle of each collider currently intersecting the given collider,
+    /// assuming one of them is a sensor. Non-intersecting pairs tracked by the narrow-phase are
+    /// skipped.
     pub fn intersections_with(&self, handle1: FlatHandle, f: js_sys::Function) {
         let this = JsValue::null();
         let handle1 = utils::collider_handle(handle1);
@@ -57,6 +66,104 @@ impl RawNarrowPhase {
         let handle2 = utils::collider_handle(handle2);
         self.0.intersection_pair(handle1, handle2) == Some(true)
     }
+
+    /// Are the two given colliders touching, i.e., do they have at least one contact point with a
+    /// distance smaller or equal to zero, as of the last step?
+    ///
+    /// Speculative contacts within the prediction distance are not considered touching.
+    pub fn areInContact(&self, handle1: FlatHandle, handle2: FlatHandle) -> bool {
+        let handle1 = utils::collider_handle(handle1);
+        let handle2 = utils::collider_handle(handle2);
+        self.0.contact_pair(handle1, handle2).map_or(false, |pair| {
+            pair.manifolds
+                .iter()
+                .any(|manifold| manifold.points.iter().any(|pt| pt.dist <= 0.0))
+        })
+    }
+
+    /// The deepest penetration depth between the two given colliders, as of the last step.
+    ///
+    /// This is the opposite of the smallest distance among their contact points, or zero if they
+    /// don't have any contact point with a negative distance.
+    pub fn penetrationDepth(&self, handle1: FlatHandle, handle2: FlatHandle) -> f32 {
+        let handle1 = utils::collider_handle(handle1);
+        let handle2 = utils::collider_handle(handle2);
+        self.0.contact_pair(handle1, handle2).map_or(0.0, |pair| {
+            pair.manifolds
+                .iter()
+                .flat_map(|manifold| manifold.points.iter())
+                .fold(0.0, |depth, pt| depth.max(-pt.dist))
+        })
+    }
+
+    /// Calls `f` for each collider in contact with a collider attached to the given rigid-body.
+    ///
+    /// # Parameters
+    /// - `dt`: the length of the last timestep, used to convert the contact impulses into forces.
+    /// - `f(collider, otherCollider, force)`: called with the handle of the rigid-body's collider,
+    ///   the handle of the other collider, and the world-space total contact force applied to the
+    ///   rigid-body's collider during the last step.
+    pub fn contactsWithRigidBody(
+        &self,
+        bodies: &RawRigidBodySet,
+        body: FlatHandle,
+        dt: f32,
+        f: &js_sys::Function,
+    ) {
+        let this = JsValue::null();
+        let inv_dt = if dt > 0.0 { 1.0 / dt } else { 0.0 };
+        // Not throwing on an invalid body: both sets would remain borrowed afterward.
+        let colliders = bodies
+            .0
+            .get(utils::body_handle(body))
+            .map(|rb| rb.colliders().to_vec())
+            .unwrap_or_default();
+
+        for handle in colliders {
+            for pair in self.0.contacts_with(handle) {
+                if !pair.has_any_active_contact {
+                    continue;
+                }
+
+                let mut impulse = Vector::zeros();
+                for manifold in &pair.manifolds {
+                    let magnitude: Real = manifold.points.iter().map(|pt| pt.data.impulse).sum();
+                    impulse += manifold.data.normal * magnitude;
+                }
+
+                // The normal points from the first collider toward the second.
+                let (other, force) = if pair.collider1 == handle {
+                    (pair.collider2, -impulse * inv_dt)
+                } else {
+                    (pair.collider1, impulse * inv_dt)
+                };
+
+                let _ = f.call3(
+                    &this,
+                    &JsValue::from(utils::flat_handle(handle.0)),
+                    &JsValue::from(utils::flat_handle(other.0)),
+                    &JsValue::from(RawVector(force)),
+                );
+            }
+        }
+    }
+
+    /// The number of pairs of colliders with at least one active contact, as of the last step.
+    pub fn numContactPairs(&self) -> usize {
+        self.0
+            .contact_pairs()
+            .filter(|pair| pair.has_any_active_contact)
+            .count()
+    }
+
+    /// The number of pairs of colliders currently intersecting, assuming one of them is a sensor,
+    /// as of the last step.
+    pub fn numIntersectionPairs(&self) -> usize {
+        self.0
+            .intersection_pairs()
+            .filter(|(_, _, intersecting)| *intersecting)
+            .count()
+    }
 }
 
 #[wasm_bindgen]
@@ -211,14 +318,34 @@ impl RawContactManifold {
     }
 
     pub fn solver_contact_friction(&self, i: usize) -> Real {
-        unsafe { (*self.0).data.solver_contacts[i].friction }
+        unsafe {
+            (*self.0)
+                .data
+                .solver_contacts
+                .get(i)
+                .map(|c| c.friction)
+                .unwrap_or(0.0)
+        }
     }
 
     pub fn solver_contact_restitution(&self, i: usize) -> Real {
-        unsafe { (*self.0).data.solver_contacts[i].restitution }
+        unsafe {
+            (*self.0)
+                .data
+                .solver_contacts
+                .get(i)
+                .map(|c| c.restitution)
+                .unwrap_or(0.0)
+        }
     }
 
-    pub fn solver_contact_tangent_velocity(&self, i: usize) -> RawVector {
-        unsafe { (*self.0).data.solver_contacts[i].tangent_velocity.into() }
+    pub fn solver_contact_tangent_velocity(&self, i: usize) -> Option<RawVector> {
+        unsafe {
+            (*self.0)
+                .data
+                .solver_contacts
+                .get(i)
+                .map(|c| c.tangent_velocity.into())
+        }
     }
 }
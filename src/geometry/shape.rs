@@ -5,10 +5,11 @@ use na::DMatrix;
 #[cfg(feature = "dim2")]
 use na::DVector;
 use na::Unit;
-use rapier::geometry::{Shape, SharedShape, TriMeshFlags};
-use rapier::math::{Isometry, Point, Real, Vector, DIM};
+use rapier::geometry::{Cuboid, HeightField, Shape, ShapeType, SharedShape, TriMeshFlags};
+use rapier::math::{AngVector, Isometry, Point, Real, Vector, DIM};
 use rapier::parry::query;
-use rapier::parry::query::Ray;
+use rapier::parry::query::{NonlinearRigidMotion, Ray};
+use rapier::parry::transformation::vhacd::VHACDParameters;
 use wasm_bindgen::prelude::*;
 
 pub trait SharedShapeUtility {
@@ -219,6 +220,57 @@ pub enum RawShapeType {
 #[wasm_bindgen]
 pub struct RawShape(pub(crate) SharedShape);
 
+// The factor of the given scale, if it is the same along every axis.
+fn uniform_scale(scale: &Vector<Real>) -> Option<Real> {
+    scale.iter().all(|s| *s == scale[0]).then(|| scale[0])
+}
+
+impl RawShape {
+    // This is a workaround because wasm-bindgen doesn't support the `cfg(feature = ...)`
+    // for the method arguments.
+    fn do_cast_shape_nonlinear(
+        &self,
+        shapePos1: &RawVector,
+        shapeRot1: &RawRotation,
+        shapeLinvel1: &RawVector,
+        shapeAngvel1: AngVector<Real>,
+        shape2: &RawShape,
+        shapePos2: &RawVector,
+        shapeRot2: &RawRotation,
+        shapeLinvel2: &RawVector,
+        shapeAngvel2: AngVector<Real>,
+        startTime: f32,
+        endTime: f32,
+        stop_at_penetration: bool,
+    ) -> Option<RawShapeTOI> {
+        let motion1 = NonlinearRigidMotion::new(
+            Isometry::from_parts(shapePos1.0.into(), shapeRot1.0),
+            Point::origin(),
+            shapeLinvel1.0,
+            shapeAngvel1,
+        );
+        let motion2 = NonlinearRigidMotion::new(
+            Isometry::from_parts(shapePos2.0.into(), shapeRot2.0),
+            Point::origin(),
+            shapeLinvel2.0,
+            shapeAngvel2,
+        );
+
+        query::nonlinear_time_of_impact(
+            &motion1,
+            &*self.0,
+            &motion2,
+            &*shape2.0,
+            startTime,
+            endTime,
+            stop_at_penetration,
+        )
+        .ok()
+        .flatten()
+        .map(|toi| RawShapeTOI { toi })
+    }
+}
+
 #[wasm_bindgen]
 impl RawShape {
     #[cfg(feature = "dim2")]
@@ -245,8 +297,11 @@ impl RawShape {
         Self(SharedShape::ball(radius))
     }
 
-    pub fn halfspace(normal: &RawVector) -> Self {
-        Self(SharedShape::halfspace(Unit::new_normalize(normal.0)))
+    /// Creates a half-space with the given outward normal.
+    ///
+    /// The normal is normalized. Returns `undefined` if the normal is zero.
+    pub fn halfspace(normal: &RawVector) -> Option<RawShape> {
+        Unit::try_new(normal.0, 1.0e-7).map(|n| Self(SharedShape::halfspace(n)))
     }
 
     pub fn capsule(halfHeight: f32, radius: f32) -> Self {
@@ -279,29 +334,95 @@ impl RawShape {
         Self(SharedShape::round_cone(halfHeight, radius, borderRadius))
     }
 
-    pub fn polyline(vertices: Vec<f32>, indices: Vec<u32>) -> Self {
+    /// Creates a polyline from a flat vertex buffer and a flat segment index buffer.
+    ///
+    /// If `indices` is empty, the vertices are connected sequentially. Returns `undefined` if the
+    /// vertex buffer length isn't a multiple of the dimension, if the index buffer length is odd,
+    /// or if an index is out of bounds.
+    pub fn polyline(vertices: Vec<f32>, indices: Vec<u32>) -> Option<RawShape> {
+        if vertices.len() % DIM != 0 || indices.len() % 2 != 0 {
+            return None;
+        }
+
+        let num_vertices = (vertices.len() / DIM) as u32;
+        if indices.iter().any(|i| *i >= num_vertices) {
+            return None;
+        }
+
         let vertices = vertices.chunks(DIM).map(|v| Point::from_slice(v)).collect();
         let indices: Vec<_> = indices.chunks(2).map(|v| [v[0], v[1]]).collect();
         if indices.is_empty() {
-            Self(SharedShape::polyline(vertices, None))
+            Some(Self(SharedShape::polyline(vertices, None)))
         } else {
-            Self(SharedShape::polyline(vertices, Some(indices)))
+            Some(Self(SharedShape::polyline(vertices, Some(indices))))
+        }
+    }
+
+    /// Decomposes a concave mesh into a compound of convex parts using VHACD.
+    ///
+    /// In 2D, `indices` describes the segments of a closed polyline. In 3D, it describes the
+    /// triangles of a closed triangle mesh. Returns `None` if the buffers have an invalid length,
+    /// if an index is out of bounds, or if the mesh is empty.
+    ///
+    /// # Parameters
+    /// - `resolution`: the voxelization resolution. Higher values preserve more detail but are slower.
+    /// - `concavity`: the maximum concavity allowed for each convex part. Lower values produce
+    ///   more parts.
+    pub fn convexDecomposition(
+        vertices: Vec<f32>,
+        indices: Vec<u32>,
+        resolution: Option<u32>,
+        concavity: Option<f32>,
+    ) -> Option<RawShape> {
+        if vertices.len() % DIM != 0 || indices.len() % DIM != 0 || indices.is_empty() {
+            return None;
+        }
+
+        let num_vertices = (vertices.len() / DIM) as u32;
+        if indices.iter().any(|i| *i >= num_vertices) {
+            return None;
+        }
+
+        let mut params = VHACDParameters::default();
+        if let Some(resolution) = resolution {
+            params.resolution = resolution;
+        }
+        if let Some(concavity) = concavity {
+            params.concavity = concavity;
         }
+
+        let vertices: Vec<_> = vertices.chunks(DIM).map(|v| Point::from_slice(v)).collect();
+        let indices: Vec<_> = indices
+            .chunks(DIM)
+            .map(|v| {
+                let mut idx = [0; DIM];
+                idx.copy_from_slice(v);
+                idx
+            })
+            .collect();
+        Some(Self(SharedShape::convex_decomposition_with_params(
+            &vertices, &indices, &params,
+        )))
     }
 
     pub fn trimesh(vertices: Vec<f32>, indices: Vec<u32>) -> Self {
-        let vertices = vertices.chunks(DIM).map(|v| Point::from_slice(v)).collect();
-        let indices = indices.chunks(3).map(|v| [v[0], v[1], v[2]]).collect();
         // NOTE: for the JS bindings, let’s just assume that the triangle mesh isn’t necessarily
-        // clean. We could provide more flexibility by allowing other flags to be given, but
-        // the API surface of the JS trimesh doesn’t really allow to benefit from other flags.
-        // So, let’s just keep MERGE_DUPLICATE_VERTICES which is useful to avoid internal edge
-        // problems.
-        Self(SharedShape::trimesh_with_flags(
+        // clean. MERGE_DUPLICATE_VERTICES is useful to avoid internal edge problems.
+        Self::trimeshWithFlags(
             vertices,
             indices,
-            TriMeshFlags::MERGE_DUPLICATE_VERTICES,
-        ))
+            TriMeshFlags::MERGE_DUPLICATE_VERTICES.bits() as u32,
+        )
+    }
+
+    /// Creates a triangle mesh, pre-processed according to the given `TriMeshFlags` bits.
+    ///
+    /// Unknown bits are ignored.
+    pub fn trimeshWithFlags(vertices: Vec<f32>, indices: Vec<u32>, flags: u32) -> Self {
+        let vertices = vertices.chunks(DIM).map(|v| Point::from_slice(v)).collect();
+        let indices = indices.chunks(3).map(|v| [v[0], v[1], v[2]]).collect();
+        let flags = TriMeshFlags::from_bits_truncate(flags as _);
+        Self(SharedShape::trimesh_with_flags(vertices, indices, flags))
     }
 
     #[cfg(feature = "dim2")]
@@ -378,6 +499,126 @@ impl RawShape {
         SharedShape::round_convex_mesh(vertices, &indices, borderRadius).map(|s| Self(s))
     }
 
+    /// Creates a copy of this shape scaled by the given factor along each local axis.
+    ///
+    /// Balls and capsules only support uniform scaling, and cylinders and cones (in 3D) require
+    /// the same factor along their `x` and `z` axes. Triangle meshes are rebuilt with the default
+    /// flags, while heightfields keep their cell statuses. Returns `undefined` if a scale factor
+    /// isn't a finite positive number, and for the shapes that can't be scaled this way, including
+    /// round shapes, half-spaces, and compound shapes.
+    pub fn scaled(&self, scale: &RawVector) -> Option<RawShape> {
+        let scale = scale.0;
+        if !scale.iter().all(|s| s.is_finite() && *s > 0.0) {
+            return None;
+        }
+
+        let scale_point = |pt: &Point<Real>| Point::from(pt.coords.component_mul(&scale));
+        let shape = &*self.0;
+
+        let scaled = match shape.shape_type() {
+            ShapeType::Ball => {
+                let ball = shape.as_ball()?;
+                SharedShape::ball(ball.radius * uniform_scale(&scale)?)
+            }
+            ShapeType::Cuboid => {
+                let cuboid = shape.as_cuboid()?;
+                SharedShape::new(Cuboid::new(cuboid.half_extents.component_mul(&scale)))
+            }
+            ShapeType::Capsule => {
+                let capsule = shape.as_capsule()?;
+                let factor = uniform_scale(&scale)?;
+                SharedShape::capsule(
+                    capsule.segment.a * factor,
+                    capsule.segment.b * factor,
+                    capsule.radius * factor,
+                )
+            }
+            ShapeType::Segment => {
+                let segment = shape.as_segment()?;
+                SharedShape::segment(scale_point(&segment.a), scale_point(&segment.b))
+            }
+            ShapeType::Triangle => {
+                let tri = shape.as_triangle()?;
+                SharedShape::triangle(
+                    scale_point(&tri.a),
+                    scale_point(&tri.b),
+                    scale_point(&tri.c),
+                )
+            }
+            ShapeType::TriMesh => {
+                let trimesh = shape.as_trimesh()?;
+                let vertices = trimesh.vertices().iter().map(scale_point).collect();
+                SharedShape::trimesh_with_flags(
+                    vertices,
+                    trimesh.indices().to_vec(),
+                    TriMeshFlags::MERGE_DUPLICATE_VERTICES,
+                )
+            }
+            ShapeType::Polyline => {
+                let polyline = shape.as_polyline()?;
+                let vertices = polyline.vertices().iter().map(scale_point).collect();
+                SharedShape::polyline(vertices, Some(polyline.indices().to_vec()))
+            }
+            ShapeType::HeightField => {
+                let heightfield = shape.as_heightfield()?;
+                let mut scaled = HeightField::new(
+                    heightfield.heights().clone(),
+                    heightfield.scale().component_mul(&scale),
+                );
+
+                #[cfg(feature = "dim2")]
+                {
+                    for k in 0..heightfield.num_cells() {
+                        scaled.set_segment_removed(k, heightfield.is_segment_removed(k));
+                    }
+                }
+
+                #[cfg(feature = "dim3")]
+                {
+                    let statuses = heightfield.cells_statuses();
+                    for i in 0..statuses.nrows() {
+                        for j in 0..statuses.ncols() {
+                            scaled.set_cell_status(i, j, statuses[(i, j)]);
+                        }
+                    }
+                }
+
+                SharedShape::new(scaled)
+            }
+            #[cfg(feature = "dim2")]
+            ShapeType::ConvexPolygon => {
+                let polygon = shape.as_convex_polygon()?;
+                let points = polygon.points().iter().map(scale_point).collect();
+                SharedShape::convex_polyline(points)?
+            }
+            #[cfg(feature = "dim3")]
+            ShapeType::ConvexPolyhedron => {
+                let polyhedron = shape.as_convex_polyhedron()?;
+                let points: Vec<_> = polyhedron.points().iter().map(scale_point).collect();
+                SharedShape::convex_hull(&points)?
+            }
+            #[cfg(feature = "dim3")]
+            ShapeType::Cylinder => {
+                let cylinder = shape.as_cylinder()?;
+                if scale.x != scale.z {
+                    return None;
+                }
+                SharedShape::cylinder(cylinder.half_height * scale.y, cylinder.radius * scale.x)
+            }
+            #[cfg(feature = "dim3")]
+            ShapeType::Cone => {
+                let cone = shape.as_cone()?;
+                if scale.x != scale.z {
+                    return None;
+                }
+                SharedShape::cone(cone.half_height * scale.y, cone.radius * scale.x)
+            }
+            _ => return None,
+        };
+
+        Some(Self(scaled))
+    }
+
     pub fn castShape(
         &self,
         shapePos1: &RawVector,
@@ -404,6 +645,80 @@ impl RawShape {
         )
     }
 
+    /// Computes the time of impact between two shapes moving with constant linear and angular
+    /// velocities.
+    ///
+    /// Each shape rotates around its own local origin. Only impacts happening between `startTime`
+    /// and `endTime` are reported.
+    #[cfg(feature = "dim2")]
+    pub fn castShapeNonlinear(
+        &self,
+        shapePos1: &RawVector,
+        shapeRot1: &RawRotation,
+        shapeLinvel1: &RawVector,
+        shapeAngvel1: f32,
+        shape2: &RawShape,
+        shapePos2: &RawVector,
+        shapeRot2: &RawRotation,
+        shapeLinvel2: &RawVector,
+        shapeAngvel2: f32,
+        startTime: f32,
+        endTime: f32,
+        stop_at_penetration: bool,
+    ) -> Option<RawShapeTOI> {
+        self.do_cast_shape_nonlinear(
+            shapePos1,
+            shapeRot1,
+            shapeLinvel1,
+            shapeAngvel1,
+            shape2,
+            shapePos2,
+            shapeRot2,
+            shapeLinvel2,
+            shapeAngvel2,
+            startTime,
+            endTime,
+            stop_at_penetration,
+        )
+    }
+
+    /// Computes the time of impact between two shapes moving with constant linear and angular
+    /// velocities.
+    ///
+    /// Each shape rotates around its own local origin. Only impacts happening between `startTime`
+    /// and `endTime` are reported.
+    #[cfg(feature = "dim3")]
+    pub fn castShapeNonlinear(
+        &self,
+        shapePos1: &RawVector,
+        shapeRot1: &RawRotation,
+        shapeLinvel1: &RawVector,
+        shapeAngvel1: &RawVector,
+        shape2: &RawShape,
+        shapePos2: &RawVector,
+        shapeRot2: &RawRotation,
+        shapeLinvel2: &RawVector,
+        shapeAngvel2: &RawVector,
+        startTime: f32,
+        endTime: f32,
+        stop_at_penetration: bool,
+    ) -> Option<RawShapeTOI> {
+        self.do_cast_shape_nonlinear(
+            shapePos1,
+            shapeRot1,
+            shapeLinvel1,
+            shapeAngvel1.0,
+            shape2,
+            shapePos2,
+            shapeRot2,
+            shapeLinvel2,
+            shapeAngvel2.0,
+            startTime,
+            endTime,
+            stop_at_penetration,
+        )
+    }
+
     pub fn intersectsShape(
         &self,
         shapePos1: &RawVector,
@@ -500,3 +815,40 @@ impl RawShape {
             .castRayAndGetNormal(&pos, rayOrig.0.into(), rayDir.0.into(), maxToi, solid)
     }
 }
+
+/// Accumulates the parts of a compound shape.
+///
+/// This is needed because wasm-bindgen doesn't support passing arrays of `RawShape`.
+#[wasm_bindgen]
+pub struct RawCompoundShapeBuilder(Vec<(Isometry<Real>, SharedShape)>);
+
+#[wasm_bindgen]
+impl RawCompoundShapeBuilder {
+    #[wasm_bindgen(constructor)]
+    pub fn new() -> Self {
+        RawCompoundShapeBuilder(vec![])
+    }
+
+    /// Adds a part to the compound shape, positioned relative to the compound's local frame.
+    pub fn push(&mut self, shape: &RawShape, translation: &RawVector, rotation: &RawRotation) {
+        let pos = Isometry::from_parts(translation.0.into(), rotation.0);
+        self.0.push((pos, shape.0.clone()));
+    }
+
+    /// Builds the compound shape from the parts added so far.
+    ///
+    /// Returns `undefined` if no part was added, or if one of the parts is itself a composite
+    /// shape (compound, triangle mesh, polyline, or heightfield) since these can't be nested.
+    pub fn build(&self) -> Option<RawShape> {
+        if self.0.is_empty()
+            || self
+                .0
+                .iter()
+                .any(|(_, shape)| shape.as_composite_shape().is_some())
+        {
+            return None;
+        }
+
+        Some(RawShape(SharedShape::compound(self.0.clone())))
+    }
+}
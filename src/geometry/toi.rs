@@ -1,6 +1,7 @@
 use crate::math::RawVector;
 use crate::utils::{self, FlatHandle};
 use rapier::geometry::{ColliderHandle, TOI};
+use rapier::math::{Point, Real, Vector};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -47,19 +48,65 @@ impl RawShapeColliderTOI {
         self.toi.toi
     }
 
+    /// The contact point on the hit collider, expressed in the collider's local-space.
     pub fn witness1(&self) -> RawVector {
         self.toi.witness1.coords.into()
     }
 
+    /// The contact point on the cast shape, expressed in the cast shape's local-space.
     pub fn witness2(&self) -> RawVector {
         self.toi.witness2.coords.into()
     }
 
+    /// The contact normal on the hit collider, expressed in the collider's local-space.
     pub fn normal1(&self) -> RawVector {
         self.toi.normal1.into_inner().into()
     }
 
+    /// The contact normal on the cast shape, expressed in the cast shape's local-space.
     pub fn normal2(&self) -> RawVector {
         self.toi.normal2.into_inner().into()
     }
 }
+
+/// The first hit of a shape-cast, with its contact points and normals expressed in world-space.
+#[wasm_bindgen]
+pub struct RawShapeCastHit {
+    pub(crate) handle: ColliderHandle,
+    pub(crate) toi: Real,
+    pub(crate) witness1: Point<Real>,
+    pub(crate) witness2: Point<Real>,
+    pub(crate) normal1: Vector<Real>,
+    pub(crate) normal2: Vector<Real>,
+}
+
+#[wasm_bindgen]
+impl RawShapeCastHit {
+    pub fn colliderHandle(&self) -> FlatHandle {
+        utils::flat_handle(self.handle.0)
+    }
+
+    pub fn toi(&self) -> f32 {
+        self.toi
+    }
+
+    /// The world-space contact point on the hit collider, at the time of impact.
+    pub fn witness1(&self) -> RawVector {
+        self.witness1.coords.into()
+    }
+
+    /// The world-space contact point on the cast shape, at the time of impact.
+    pub fn witness2(&self) -> RawVector {
+        self.witness2.coords.into()
+    }
+
+    /// The world-space contact normal on the hit collider, at the time of impact.
+    pub fn normal1(&self) -> RawVector {
+        self.normal1.into()
+    }
+
+    /// The world-space contact normal on the cast shape, at the time of impact.
+    pub fn normal2(&self) -> RawVector {
+        self.normal2.into()
+    }
+}
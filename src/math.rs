@@ -70,6 +70,28 @@ impl RawRotation {
         Self(Rotation::identity())
     }
 
+    /// The rotation built from the given Euler angles, in radians.
+    ///
+    /// The rotations are applied in the order roll, pitch, yaw: the resulting rotation
+    /// is `Rz(yaw) * Ry(pitch) * Rx(roll)`.
+    ///
+    /// # Parameters
+    /// - `roll`: the rotation angle around the `x` axis.
+    /// - `pitch`: the rotation angle around the `y` axis.
+    /// - `yaw`: the rotation angle around the `z` axis.
+    pub fn fromEuler(roll: f32, pitch: f32, yaw: f32) -> Self {
+        Self(Rotation::from_euler_angles(roll, pitch, yaw))
+    }
+
+    /// The Euler angles of this rotation, in radians.
+    ///
+    /// Returns a vector with the roll, pitch, and yaw angles as its `x`, `y`, and `z`
+    /// components, using the same convention as `RawRotation.fromEuler`.
+    pub fn toEuler(&self) -> RawVector {
+        let (roll, pitch, yaw) = self.0.euler_angles();
+        RawVector(Vector::new(roll, pitch, yaw))
+    }
+
     /// The `x` component of this quaternion.
     #[wasm_bindgen(getter)]
     pub fn x(&self) -> f32 {
@@ -238,6 +260,59 @@ impl RawVector {
     pub fn zyx(&self) -> Self {
         Self(self.0.zyx())
     }
+
+    /// The sum of this vector and `other`.
+    pub fn add(&self, other: &RawVector) -> Self {
+        Self(self.0 + other.0)
+    }
+
+    /// The difference between this vector and `other`.
+    pub fn sub(&self, other: &RawVector) -> Self {
+        Self(self.0 - other.0)
+    }
+
+    /// This vector multiplied by the scalar `factor`.
+    pub fn scale(&self, factor: f32) -> Self {
+        Self(self.0 * factor)
+    }
+
+    /// The dot product of this vector and `other`.
+    pub fn dot(&self, other: &RawVector) -> f32 {
+        self.0.dot(&other.0)
+    }
+
+    /// The cross product of this vector and `other`.
+    #[cfg(feature = "dim3")]
+    pub fn cross(&self, other: &RawVector) -> Self {
+        Self(self.0.cross(&other.0))
+    }
+
+    /// The length of this vector.
+    pub fn length(&self) -> f32 {
+        self.0.norm()
+    }
+
+    /// This vector divided by its length.
+    ///
+    /// Returns `None` if the length of this vector is too close to zero.
+    pub fn normalize(&self) -> Option<RawVector> {
+        self.0.try_normalize(1.0e-7).map(Self)
+    }
+
+    /// Adds `other` to this vector, in-place.
+    pub fn addAssign(&mut self, other: &RawVector) {
+        self.0 += other.0
+    }
+
+    /// Subtracts `other` from this vector, in-place.
+    pub fn subAssign(&mut self, other: &RawVector) {
+        self.0 -= other.0
+    }
+
+    /// Multiplies this vector by the scalar `factor`, in-place.
+    pub fn scaleAssign(&mut self, factor: f32) {
+        self.0 *= factor
+    }
 }
 
 #[wasm_bindgen]
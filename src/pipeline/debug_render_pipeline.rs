@@ -5,7 +5,9 @@ use palette::convert::IntoColorUnclamped;
 use palette::rgb::Rgba;
 use palette::Hsla;
 use rapier::math::{Point, Real};
-use rapier::pipeline::{DebugRenderBackend, DebugRenderObject, DebugRenderPipeline};
+use rapier::pipeline::{
+    DebugRenderBackend, DebugRenderMode, DebugRenderObject, DebugRenderPipeline,
+};
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -26,6 +28,18 @@ impl RawDebugRenderPipeline {
         }
     }
 
+    /// The bit flags selecting which elements are rendered (see `DebugRenderMode`).
+    #[wasm_bindgen(getter)]
+    pub fn mode(&self) -> u32 {
+        self.raw.mode.bits()
+    }
+
+    /// Unknown bits are ignored rather than disabling every other flag.
+    #[wasm_bindgen(setter)]
+    pub fn set_mode(&mut self, mode: u32) {
+        self.raw.mode = DebugRenderMode::from_bits_truncate(mode);
+    }
+
     pub fn vertices(&self) -> Float32Array {
         let output = Float32Array::new_with_length(self.vertices.len() as u32);
         output.copy_from(&self.vertices);
@@ -1,17 +1,81 @@
 use crate::math::RawVector;
 use crate::utils;
 use crate::utils::FlatHandle;
-use rapier::crossbeam::channel::Receiver;
-use rapier::geometry::{CollisionEvent, ContactForceEvent};
-use rapier::pipeline::ChannelEventCollector;
+use rapier::crossbeam::channel::{Receiver, Sender};
+use rapier::dynamics::RigidBodySet;
+use rapier::geometry::{ColliderSet, CollisionEvent, ContactForceEvent, ContactPair};
+use rapier::math::{Point, Real, Vector};
+use rapier::pipeline::EventHandler;
 use wasm_bindgen::prelude::*;
 
+// The world-space normal and deepest contact point of a contact pair, captured when its
+// collision event is emitted.
+#[derive(Copy, Clone)]
+struct CollisionContact {
+    normal: Vector<Real>,
+    point: Point<Real>,
+}
+
+fn deepest_contact(colliders: &ColliderSet, pair: &ContactPair) -> Option<CollisionContact> {
+    let (manifold, contact) = pair.find_deepest_contact()?;
+    let mut pos1 = *colliders.get(pair.collider1)?.position();
+    if let Some(subshape_pos1) = manifold.subshape_pos1 {
+        pos1 *= subshape_pos1;
+    }
+
+    Some(CollisionContact {
+        normal: pos1 * manifold.local_n1,
+        point: pos1 * contact.local_p1,
+    })
+}
+
+/// An event handler sending the events to channels, together with the contact data of the
+/// collision events that started.
+pub(crate) struct RawEventCollector {
+    collision_events: Sender<(CollisionEvent, Option<CollisionContact>)>,
+    contact_force_events: Sender<ContactForceEvent>,
+    sensor_events_only: bool,
+}
+
+impl EventHandler for RawEventCollector {
+    fn handle_collision_event(
+        &self,
+        _bodies: &RigidBodySet,
+        colliders: &ColliderSet,
+        event: CollisionEvent,
+        contact_pair: Option<&ContactPair>,
+    ) {
+        if self.sensor_events_only && !event.sensor() {
+            return;
+        }
+
+        let contact = if event.started() {
+            contact_pair.and_then(|pair| deepest_contact(colliders, pair))
+        } else {
+            None
+        };
+        let _ = self.collision_events.send((event, contact));
+    }
+
+    fn handle_contact_force_event(
+        &self,
+        dt: Real,
+        _bodies: &RigidBodySet,
+        _colliders: &ColliderSet,
+        contact_pair: &ContactPair,
+        total_force_magnitude: Real,
+    ) {
+        let event = ContactForceEvent::from_contact_pair(dt, contact_pair, total_force_magnitude);
+        let _ = self.contact_force_events.send(event);
+    }
+}
+
 /// A structure responsible for collecting events generated
 /// by the physics engine.
 #[wasm_bindgen]
 pub struct RawEventQueue {
-    pub(crate) collector: ChannelEventCollector,
-    collision_events: Receiver<CollisionEvent>,
+    pub(crate) collector: RawEventCollector,
+    collision_events: Receiver<(CollisionEvent, Option<CollisionContact>)>,
     contact_force_events: Receiver<ContactForceEvent>,
     pub(crate) auto_drain: bool,
 }
@@ -80,7 +144,11 @@ impl RawEventQueue {
     pub fn new(autoDrain: bool) -> Self {
         let collision_channel = rapier::crossbeam::channel::unbounded();
         let contact_force_channel = rapier::crossbeam::channel::unbounded();
-        let collector = ChannelEventCollector::new(collision_channel.0, contact_force_channel.0);
+        let collector = RawEventCollector {
+            collision_events: collision_channel.0,
+            contact_force_events: contact_force_channel.0,
+            sensor_events_only: false,
+        };
 
         Self {
             collector,
@@ -100,7 +168,7 @@ impl RawEventQueue {
     /// (false).
     pub fn drainCollisionEvents(&mut self, f: &js_sys::Function) {
         let this = JsValue::null();
-        while let Ok(event) = self.collision_events.try_recv() {
+        while let Ok((event, _)) = self.collision_events.try_recv() {
             match event {
                 CollisionEvent::Started(co1, co2, _) => {
                     let h1 = utils::flat_handle(co1.0);
@@ -126,6 +194,51 @@ impl RawEventQueue {
         }
     }
 
+    /// Applies the given javascript closure on each collision event of this collector, together with
+    /// the contact data of the collisions that started, then clear the internal collision event buffer.
+    ///
+    /// # Parameters
+    /// - `f(handle1, handle2, started, normal, point)`: JavaScript closure applied to each collision
+    /// event. The first three arguments are the same as for `drainCollisionEvents`. If the collision
+    /// started and involves a contact (not a sensor intersection), `normal` is the world-space contact
+    /// normal pointing from the first collider toward the second, and `point` is the world-space
+    /// position of the deepest contact point on the first collider. Otherwise, both are `null`.
+    pub fn drainCollisionEventsWithContacts(&mut self, f: &js_sys::Function) {
+        let this = JsValue::null();
+        while let Ok((event, contact)) = self.collision_events.try_recv() {
+            let (co1, co2, started) = match event {
+                CollisionEvent::Started(co1, co2, _) => (co1, co2, true),
+                CollisionEvent::Stopped(co1, co2, _) => (co1, co2, false),
+            };
+            let (normal, point) = match contact {
+                Some(contact) => (
+                    JsValue::from(RawVector(contact.normal)),
+                    JsValue::from(RawVector(contact.point.coords)),
+                ),
+                None => (JsValue::NULL, JsValue::NULL),
+            };
+
+            let args = js_sys::Array::of5(
+                &JsValue::from(utils::flat_handle(co1.0)),
+                &JsValue::from(utils::flat_handle(co2.0)),
+                &JsValue::from_bool(started),
+                &normal,
+                &point,
+            );
+            let _ = f.apply(&this, &args);
+        }
+    }
+
+    /// Applies the given javascript closure on each contact force event of this collector, then clear
+    /// the internal contact force event buffer.
+    ///
+    /// Contact force events are only generated for colliders with the `CONTACT_FORCE_EVENTS` active
+    /// event enabled, and when the total force magnitude exceeds the collider's contact force event
+    /// threshold.
+    ///
+    /// # Parameters
+    /// - `f(event)`: JavaScript closure applied to each contact force event. The closure is called
+    /// with a `RawContactForceEvent` as its only argument.
     pub fn drainContactForceEvents(&mut self, f: &js_sys::Function) {
         let this = JsValue::null();
         while let Ok(event) = self.contact_force_events.try_recv() {
@@ -133,8 +246,23 @@ impl RawEventQueue {
         }
     }
 
+    /// Sets whether collision events between two non-sensor colliders are discarded.
+    ///
+    /// When enabled, only the collision events involving at least one sensor collider are
+    /// collected, so solid contacts don't have to be marshalled to JavaScript. Contact force events
+    /// are unaffected and can be drained independently with `drainContactForceEvents`.
+    pub fn setSensorEventsOnly(&mut self, enabled: bool) {
+        self.collector.sensor_events_only = enabled;
+    }
+
+    /// Are collision events between two non-sensor colliders discarded?
+    pub fn sensorEventsOnly(&self) -> bool {
+        self.collector.sensor_events_only
+    }
+
     /// Removes all events contained by this collector.
     pub fn clear(&self) {
         while let Ok(_) = self.collision_events.try_recv() {}
+        while let Ok(_) = self.contact_force_events.try_recv() {}
     }
 }
@@ -1,15 +1,24 @@
 use crate::utils;
 use rapier::geometry::SolverFlags;
+use rapier::math::DIM;
 use rapier::pipeline::{ContactModificationContext, PairFilterContext, PhysicsHooks};
 use wasm_bindgen::prelude::*;
 
+/// Physics hooks forwarding the pair filtering to JavaScript callbacks.
+///
+/// Every call crosses the WASM boundary, which is much more costly than a native hook. They
+/// are only called for pairs where at least one collider enabled the corresponding active hook.
 pub struct RawPhysicsHooks {
     pub this: js_sys::Object,
     pub filter_contact_pair: js_sys::Function,
     pub filter_intersection_pair: js_sys::Function,
-    // pub modify_solver_contacts: &'a js_sys::Function,
+    pub modify_solver_contacts: js_sys::Function,
 }
 
+/// The number of floats describing each solver contact given to the JS `modifySolverContacts`
+/// hook: the friction, the restitution, then the `DIM` components of the tangent velocity.
+pub const SOLVER_CONTACT_STRIDE: usize = 2 + DIM;
+
 #[wasm_bindgen]
 extern "C" {
     // Use `js_namespace` here to bind `console.log(..)` instead of just
@@ -66,145 +75,55 @@ impl PhysicsHooks for RawPhysicsHooks {
             .unwrap_or(false)
     }
 
-    fn modify_solver_contacts(&self, _ctxt: &mut ContactModificationContext) {}
-}
-
-/* NOTE: the following is an attempt to make contact modification work.
- *
-#[wasm_bindgen]
-#[derive(Copy, Clone, Debug)]
-pub struct RawContactManifold(*const ContactManifold);
-pub struct RawSolverContact(*const SolverContact);
-
-#[wasm_bindgen]
-pub struct RawContactModificationContext {
-    pub collider1: u32,
-    pub collider2: u32,
-    pub rigid_body1: Option<u32>,
-    pub rigid_body2: Option<u32>,
-    pub manifold: *const ContactManifold,
-    pub solver_contacts: *mut Vec<SolverContact>,
-    normal: *mut Vector<Real>,
-    user_data: *mut u32,
-}
-
-#[wasm_bindgen]
-impl RawContactModificationContext {
-    pub fn collider1(&self) -> u32 {
-        self.collider1
-    }
-
-    pub fn collider2(&self) -> u32 {
-        self.collider2
-    }
-
-    #[wasm_bindgen(getter)]
-    pub fn normal(&self) -> RawVector {
-        unsafe { RawVector(*self.normal) }
-    }
-
-    #[wasm_bindgen(setter)]
-    pub fn set_normal(&mut self, normal: RawVector) {
-        unsafe {
-            *self.normal = normal.0;
-        }
-    }
-
-    #[wasm_bindgen(getter)]
-    pub fn user_data(&self) -> u32 {
-        unsafe { *self.user_data }
-    }
-
-    #[wasm_bindgen(setter)]
-    pub fn set_user_data(&mut self, user_data: u32) {
-        unsafe {
-            *self.user_data = user_data;
-        }
-    }
-
-    pub fn num_solver_contacts(&self) -> usize {
-        unsafe { (*self.solver_contacts).len() }
-    }
-
-    pub fn clear_solver_contacts(&mut self) {
-        unsafe { (*self.solver_contacts).clear() }
-    }
-
-    pub fn remove_solver_contact(&mut self, i: usize) {
-        unsafe {
-            if i < self.num_solver_contacts() {
-                (*self.solver_contacts).swap_remove(i);
-            }
-        }
-    }
-
-    pub fn solver_contact_point(&self, i: usize) -> Option<RawVector> {
-        unsafe {
-            (*self.solver_contacts)
-                .get(i)
-                .map(|c| c.point.coords.into())
+    fn modify_solver_contacts(&self, ctxt: &mut ContactModificationContext) {
+        if !self.modify_solver_contacts.is_function() || ctxt.solver_contacts.is_empty() {
+            return;
         }
-    }
-
-    pub fn set_solver_contact_point(&mut self, i: usize, pt: &RawVector) {
-        unsafe {
-            if let Some(c) = (*self.solver_contacts).get_mut(i) {
-                c.point = pt.0.into()
-            }
-        }
-    }
 
-    pub fn solver_contact_dist(&self, i: usize) -> Real {
-        unsafe {
-            (*self.solver_contacts)
-                .get(i)
-                .map(|c| c.dist)
-                .unwrap_or(0.0)
-        }
-    }
-
-    pub fn set_solver_contact_dist(&mut self, i: usize, dist: Real) {
-        unsafe {
-            if let Some(c) = (*self.solver_contacts).get_mut(i) {
-                c.dist = dist
-            }
-        }
-    }
-
-    pub fn solver_contact_friction(&self, i: usize) -> Real {
-        unsafe { (*self.solver_contacts)[i].friction }
-    }
+        let rb1 = ctxt
+            .rigid_body1
+            .map(|rb| JsValue::from(utils::flat_handle(rb.0)))
+            .unwrap_or(JsValue::NULL);
+        let rb2 = ctxt
+            .rigid_body2
+            .map(|rb| JsValue::from(utils::flat_handle(rb.0)))
+            .unwrap_or(JsValue::NULL);
 
-    pub fn set_solver_contact_friction(&mut self, i: usize, friction: Real) {
-        unsafe {
-            if let Some(c) = (*self.solver_contacts).get_mut(i) {
-                c.friction = friction
-            }
+        // The solver contacts are copied to a JS array the hook can modify in-place,
+        // then copied back once it returns.
+        let mut data = Vec::with_capacity(ctxt.solver_contacts.len() * SOLVER_CONTACT_STRIDE);
+        for contact in ctxt.solver_contacts.iter() {
+            data.push(contact.friction);
+            data.push(contact.restitution);
+            data.extend_from_slice(contact.tangent_velocity.as_slice());
         }
-    }
+        let contacts = js_sys::Float32Array::from(&data[..]);
 
-    pub fn solver_contact_restitution(&self, i: usize) -> Real {
-        unsafe { (*self.solver_contacts)[i].restitution }
-    }
+        let result = self
+            .modify_solver_contacts
+            .bind2(
+                &self.this,
+                &JsValue::from(utils::flat_handle(ctxt.collider1.0)),
+                &JsValue::from(utils::flat_handle(ctxt.collider2.0)),
+            )
+            .call3(&self.this, &rb1, &rb2, &contacts);
 
-    pub fn set_solver_contact_restitution(&mut self, i: usize, restitution: Real) {
-        unsafe {
-            if let Some(c) = (*self.solver_contacts).get_mut(i) {
-                c.restitution = restitution
-            }
+        if result.is_err() {
+            return;
         }
-    }
-
-    pub fn solver_contact_tangent_velocity(&self, i: usize) -> RawVector {
-        unsafe { (*self.solver_contacts)[i].tangent_velocity.into() }
-    }
 
-    pub fn set_solver_contact_tangent_velocity(&mut self, i: usize, vel: &RawVector) {
-        unsafe {
-            if let Some(c) = (*self.solver_contacts).get_mut(i) {
-                c.tangent_velocity = vel.0.into()
-            }
+        contacts.copy_to(&mut data);
+        for (contact, values) in ctxt
+            .solver_contacts
+            .iter_mut()
+            .zip(data.chunks_exact(SOLVER_CONTACT_STRIDE))
+        {
+            contact.friction = values[0];
+            contact.restitution = values[1];
+            contact
+                .tangent_velocity
+                .as_mut_slice()
+                .copy_from_slice(&values[2..]);
         }
     }
 }
-*/
@@ -5,12 +5,69 @@ use crate::dynamics::{
 use crate::geometry::{RawBroadPhase, RawColliderSet, RawNarrowPhase};
 use crate::math::RawVector;
 use crate::pipeline::{RawEventQueue, RawPhysicsHooks};
-use crate::rapier::pipeline::PhysicsPipeline;
+use crate::rapier::pipeline::{EventHandler, PhysicsHooks, PhysicsPipeline};
 use wasm_bindgen::prelude::*;
 
+/// The physics pipeline, responsible for advancing the simulation.
+///
+/// Rapier is built without its `parallel` feature, so the pipeline always runs serially on the
+/// calling thread and doesn't require WASM threads, `SharedArrayBuffer`, or cross-origin isolation.
 #[wasm_bindgen]
 pub struct RawPhysicsPipeline(pub(crate) PhysicsPipeline);
 
+impl RawPhysicsPipeline {
+    // Runs `substeps` consecutive steps, each with a timestep length of `dt / substeps`.
+    //
+    // The position-based kinematic rigid-bodies are moved along their path by a fraction
+    // `1 / substeps` of their motion at each substep, so that their velocity is the same as with
+    // a single step.
+    fn do_step_substepped(
+        &mut self,
+        gravity: &RawVector,
+        integrationParameters: &RawIntegrationParameters,
+        islands: &mut RawIslandManager,
+        broadPhase: &mut RawBroadPhase,
+        narrowPhase: &mut RawNarrowPhase,
+        bodies: &mut RawRigidBodySet,
+        colliders: &mut RawColliderSet,
+        joints: &mut RawImpulseJointSet,
+        articulations: &mut RawMultibodyJointSet,
+        ccd_solver: &mut RawCCDSolver,
+        substeps: u32,
+        hooks: &dyn PhysicsHooks,
+        events: &dyn EventHandler,
+    ) {
+        let substeps = substeps.max(1);
+        let mut params = integrationParameters.0;
+        params.dt /= substeps as f32;
+        let kinematic_motions = bodies.kinematic_motions();
+
+        for k in 0..substeps {
+            bodies.interpolate_kinematic_motions(
+                &kinematic_motions,
+                (k + 1) as f32 / substeps as f32,
+            );
+            bodies.clamp_velocities();
+            self.0.step(
+                &gravity.0,
+                &params,
+                &mut islands.0,
+                &mut broadPhase.0,
+                &mut narrowPhase.0,
+                &mut bodies.0,
+                &mut colliders.0,
+                &mut joints.0,
+                &mut articulations.0,
+                &mut ccd_solver.0,
+                None,
+                hooks,
+                events,
+            );
+            bodies.clamp_velocities();
+        }
+    }
+}
+
 #[wasm_bindgen]
 impl RawPhysicsPipeline {
     #[wasm_bindgen(constructor)]
@@ -18,6 +75,14 @@ impl RawPhysicsPipeline {
         RawPhysicsPipeline(PhysicsPipeline::new())
     }
 
+    /// Advances the simulation by one timestep.
+    ///
+    /// The `gravity` is read again at each call, so it can be changed freely between two steps.
+    /// It applies to every dynamic rigid-body, scaled by each body's gravity scale.
+    ///
+    /// The broad-phase, narrow-phase, solver, integration and CCD stages all run within this call
+    /// and the sets it borrows can't be accessed from JavaScript until it returns. The physics
+    /// hooks of `stepWithEvents` are the only JavaScript code that can run between these stages.
     pub fn step(
         &mut self,
         gravity: &RawVector,
@@ -31,6 +96,7 @@ impl RawPhysicsPipeline {
         articulations: &mut RawMultibodyJointSet,
         ccd_solver: &mut RawCCDSolver,
     ) {
+        bodies.clamp_velocities();
         self.0.step(
             &gravity.0,
             &integrationParameters.0,
@@ -46,6 +112,7 @@ impl RawPhysicsPipeline {
             &(),
             &(),
         );
+        bodies.clamp_velocities();
     }
 
     pub fn stepWithEvents(
@@ -64,6 +131,7 @@ impl RawPhysicsPipeline {
         hookObject: js_sys::Object,
         hookFilterContactPair: js_sys::Function,
         hookFilterIntersectionPair: js_sys::Function,
+        hookModifySolverContacts: js_sys::Function,
     ) {
         if eventQueue.auto_drain {
             eventQueue.clear();
@@ -73,8 +141,10 @@ impl RawPhysicsPipeline {
             this: hookObject,
             filter_contact_pair: hookFilterContactPair,
             filter_intersection_pair: hookFilterIntersectionPair,
+            modify_solver_contacts: hookModifySolverContacts,
         };
 
+        bodies.clamp_velocities();
         self.0.step(
             &gravity.0,
             &integrationParameters.0,
@@ -90,5 +160,94 @@ impl RawPhysicsPipeline {
             &hooks,
             &eventQueue.collector,
         );
+        bodies.clamp_velocities();
+    }
+
+    /// Advances the simulation by `integrationParameters.dt`, split into `substeps` steps of
+    /// equal length `dt / substeps`.
+    ///
+    /// The whole `dt` is always consumed: no remainder time is accumulated between calls. If the
+    /// frame time varies, it is up to the caller to accumulate it and choose `dt` accordingly.
+    /// A `substeps` value of zero is treated as one.
+    pub fn stepSubstepped(
+        &mut self,
+        gravity: &RawVector,
+        integrationParameters: &RawIntegrationParameters,
+        islands: &mut RawIslandManager,
+        broadPhase: &mut RawBroadPhase,
+        narrowPhase: &mut RawNarrowPhase,
+        bodies: &mut RawRigidBodySet,
+        colliders: &mut RawColliderSet,
+        joints: &mut RawImpulseJointSet,
+        articulations: &mut RawMultibodyJointSet,
+        ccd_solver: &mut RawCCDSolver,
+        substeps: u32,
+    ) {
+        self.do_step_substepped(
+            gravity,
+            integrationParameters,
+            islands,
+            broadPhase,
+            narrowPhase,
+            bodies,
+            colliders,
+            joints,
+            articulations,
+            ccd_solver,
+            substeps,
+            &(),
+            &(),
+        );
+    }
+
+    /// Same as `stepSubstepped` but with events and physics hooks.
+    ///
+    /// If the event queue is auto-drained, it is only drained once before the first substep, so
+    /// it ends up containing the events of all the substeps.
+    pub fn stepSubsteppedWithEvents(
+        &mut self,
+        gravity: &RawVector,
+        integrationParameters: &RawIntegrationParameters,
+        islands: &mut RawIslandManager,
+        broadPhase: &mut RawBroadPhase,
+        narrowPhase: &mut RawNarrowPhase,
+        bodies: &mut RawRigidBodySet,
+        colliders: &mut RawColliderSet,
+        joints: &mut RawImpulseJointSet,
+        articulations: &mut RawMultibodyJointSet,
+        ccd_solver: &mut RawCCDSolver,
+        eventQueue: &mut RawEventQueue,
+        hookObject: js_sys::Object,
+        hookFilterContactPair: js_sys::Function,
+        hookFilterIntersectionPair: js_sys::Function,
+        hookModifySolverContacts: js_sys::Function,
+        substeps: u32,
+    ) {
+        if eventQueue.auto_drain {
+            eventQueue.clear();
+        }
+
+        let hooks = RawPhysicsHooks {
+            this: hookObject,
+            filter_contact_pair: hookFilterContactPair,
+            filter_intersection_pair: hookFilterIntersectionPair,
+            modify_solver_contacts: hookModifySolverContacts,
+        };
+
+        self.do_step_substepped(
+            gravity,
+            integrationParameters,
+            islands,
+            broadPhase,
+            narrowPhase,
+            bodies,
+            colliders,
+            joints,
+            articulations,
+            ccd_solver,
+            substeps,
+            &hooks,
+            &eventQueue.collector,
+        );
     }
 }
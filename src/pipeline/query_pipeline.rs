@@ -1,12 +1,12 @@
 use crate::dynamics::RawRigidBodySet;
 use crate::geometry::{
     RawColliderSet, RawPointColliderProjection, RawRayColliderIntersection, RawRayColliderToi,
-    RawShape, RawShapeColliderTOI,
+    RawShape, RawShapeCastHit, RawShapeColliderTOI,
 };
 use crate::math::{RawRotation, RawVector};
 use crate::utils::{self, FlatHandle};
 use rapier::geometry::{Aabb, ColliderHandle, Ray};
-use rapier::math::{Isometry, Point};
+use rapier::math::{Isometry, Point, Vector, DIM};
 use rapier::pipeline::{QueryFilter, QueryFilterFlags, QueryPipeline};
 use rapier::prelude::FeatureId;
 use wasm_bindgen::prelude::*;
@@ -21,6 +21,16 @@ impl RawQueryPipeline {
         RawQueryPipeline(QueryPipeline::new())
     }
 
+    /// Removes all the colliders tracked by this query pipeline.
+    pub fn clear(&mut self) {
+        self.0 = QueryPipeline::new();
+    }
+
+    /// Updates the acceleration structure with the current positions of the colliders.
+    ///
+    /// This is independent from the physics step: queries made before this update see the
+    /// collider positions of the previous update. Collider positions must be propagated from
+    /// manually moved rigid-bodies beforehand with `propagateModifiedBodyPositionsToColliders`.
     pub fn update(&mut self, bodies: &RawRigidBodySet, colliders: &RawColliderSet) {
         self.0.update(&bodies.0, &colliders.0);
     }
@@ -57,6 +67,62 @@ impl RawQueryPipeline {
         Some(RawRayColliderToi { handle, toi })
     }
 
+    /// Casts many rays at once, finding the closest collider hit by each one of them.
+    ///
+    /// The ray origins and directions are given as flat arrays of `DIM` components per ray. The
+    /// result contains two numbers per ray: its time-of-impact followed by the handle of the
+    /// collider it hit, both set to `NaN` if the ray didn't hit anything. This performs a single
+    /// WASM call for the whole batch.
+    pub fn castRays(
+        &self,
+        bodies: &RawRigidBodySet,
+        colliders: &RawColliderSet,
+        rayOrigs: Vec<f32>,
+        rayDirs: Vec<f32>,
+        maxToi: f32,
+        solid: bool,
+        filter_flags: u32,
+        filter_groups: Option<u32>,
+        filter_exclude_collider: Option<FlatHandle>,
+        filter_exclude_rigid_body: Option<FlatHandle>,
+        filter_predicate: &js_sys::Function,
+    ) -> Vec<f64> {
+        utils::with_filter(filter_predicate, |predicate| {
+            let query_filter = QueryFilter {
+                flags: QueryFilterFlags::from_bits(filter_flags)
+                    .unwrap_or(QueryFilterFlags::empty()),
+                groups: filter_groups.map(crate::geometry::unpack_interaction_groups),
+                exclude_collider: filter_exclude_collider.map(crate::utils::collider_handle),
+                exclude_rigid_body: filter_exclude_rigid_body.map(crate::utils::body_handle),
+                predicate,
+            };
+
+            rayOrigs
+                .chunks_exact(DIM)
+                .zip(rayDirs.chunks_exact(DIM))
+                .flat_map(|(orig, dir)| {
+                    let ray = Ray::new(Point::from_slice(orig), Vector::from_row_slice(dir));
+                    match self.0.cast_ray(
+                        &bodies.0,
+                        &colliders.0,
+                        &ray,
+                        maxToi,
+                        solid,
+                        query_filter,
+                    ) {
+                        Some((handle, toi)) => [toi as f64, utils::flat_handle(handle.0)],
+                        None => [f64::NAN, f64::NAN],
+                    }
+                })
+                .collect()
+        })
+    }
+
+    /// Finds the closest collider hit by a ray, along with the world-space normal at the hit point.
+    ///
+    /// If `solid` is `true` and the ray origin lies inside of a shape, the hit is reported with a
+    /// time-of-impact of zero and a zero normal. If `solid` is `false`, shapes are treated as hollow
+    /// and the ray hits their boundary from the inside instead.
     pub fn castRayAndGetNormal(
         &self,
         bodies: &RawRigidBodySet,
@@ -96,6 +162,8 @@ impl RawQueryPipeline {
     }
 
     // The callback is of type (RawRayColliderIntersection) => bool
+    // Hits are reported in no particular order (they are not sorted by time-of-impact).
+    // Returning `false` from the callback stops the traversal early.
     pub fn intersectionsWithRay(
         &self,
         bodies: &RawRigidBodySet,
@@ -172,6 +240,10 @@ impl RawQueryPipeline {
         })
     }
 
+    /// Projects a point on the closest collider.
+    ///
+    /// The feature of the returned projection is always unknown. Use `projectPointAndGetFeature`
+    /// if the projected feature is needed.
     pub fn projectPoint(
         &self,
         bodies: &RawRigidBodySet,
@@ -246,7 +318,7 @@ impl RawQueryPipeline {
         })
     }
 
-    // The callback is of type (u32) => bool
+    // The callback is of type (FlatHandle) => bool and is called once per collider containing the point.
     pub fn intersectionsWithPoint(
         &self,
         bodies: &RawRigidBodySet,
@@ -328,7 +400,66 @@ impl RawQueryPipeline {
         })
     }
 
-    // The callback has type (u32) => boolean
+    /// Casts a shape like `castShape`, but returns the contact points and normals in world-space.
+    ///
+    /// The points are given at the time of impact, i.e., after the cast shape moved by
+    /// `shapeVel * toi`.
+    pub fn castShapeWithWitness(
+        &self,
+        bodies: &RawRigidBodySet,
+        colliders: &RawColliderSet,
+        shapePos: &RawVector,
+        shapeRot: &RawRotation,
+        shapeVel: &RawVector,
+        shape: &RawShape,
+        maxToi: f32,
+        stop_at_penetration: bool,
+        filter_flags: u32,
+        filter_groups: Option<u32>,
+        filter_exclude_collider: Option<FlatHandle>,
+        filter_exclude_rigid_body: Option<FlatHandle>,
+        filter_predicate: &js_sys::Function,
+    ) -> Option<RawShapeCastHit> {
+        utils::with_filter(filter_predicate, |predicate| {
+            let query_filter = QueryFilter {
+                flags: QueryFilterFlags::from_bits(filter_flags)
+                    .unwrap_or(QueryFilterFlags::empty()),
+                groups: filter_groups.map(crate::geometry::unpack_interaction_groups),
+                exclude_collider: filter_exclude_collider.map(crate::utils::collider_handle),
+                exclude_rigid_body: filter_exclude_rigid_body.map(crate::utils::body_handle),
+                predicate,
+            };
+
+            let pos = Isometry::from_parts(shapePos.0.into(), shapeRot.0);
+            let (handle, toi) = self.0.cast_shape(
+                &bodies.0,
+                &colliders.0,
+                &pos,
+                &shapeVel.0,
+                &*shape.0,
+                maxToi,
+                stop_at_penetration,
+                query_filter,
+            )?;
+
+            let co_pos = colliders.0.get(handle)?.position();
+            let mut pos_at_toi = pos;
+            pos_at_toi.translation.vector += shapeVel.0 * toi.toi;
+
+            Some(RawShapeCastHit {
+                handle,
+                toi: toi.toi,
+                witness1: co_pos * toi.witness1,
+                witness2: pos_at_toi * toi.witness2,
+                normal1: co_pos * toi.normal1.into_inner(),
+                normal2: pos_at_toi * toi.normal2.into_inner(),
+            })
+        })
+    }
+
+    // The callback has type (FlatHandle) => boolean
+    // Sensors and colliders attached to sleeping bodies are reported too, unless excluded by the
+    // filter flags (e.g. `EXCLUDE_SENSORS`).
     pub fn intersectionsWithShape(
         &self,
         bodies: &RawRigidBodySet,
@@ -373,6 +504,10 @@ impl RawQueryPipeline {
         })
     }
 
+    /// Calls `callback` with the handle of each collider with an AABB intersecting the given AABB.
+    ///
+    /// Only bounding boxes are compared, using the acceleration structure of this pipeline, so the
+    /// results reflect its last update. The search stops when `callback` returns `false`.
     pub fn collidersWithAabbIntersectingAabb(
         &self,
         aabbCenter: &RawVector,
@@ -1,12 +1,13 @@
 use crate::dynamics::{
     RawImpulseJointSet, RawIntegrationParameters, RawIslandManager, RawMultibodyJointSet,
-    RawRigidBodySet,
+    RawRigidBodySet, VelocityLimits,
 };
 use crate::geometry::{RawBroadPhase, RawColliderSet, RawNarrowPhase};
 use crate::math::RawVector;
 use js_sys::Uint8Array;
 use rapier::dynamics::{
-    ImpulseJointSet, IntegrationParameters, IslandManager, MultibodyJointSet, RigidBodySet,
+    ImpulseJointSet, IntegrationParameters, IslandManager, MultibodyJointSet, RigidBodyHandle,
+    RigidBodySet,
 };
 use rapier::geometry::{BroadPhase, ColliderSet, NarrowPhase};
 use rapier::math::Vector;
@@ -23,6 +24,7 @@ struct SerializableWorld<'a> {
     colliders: &'a ColliderSet,
     impulse_joints: &'a ImpulseJointSet,
     multibody_joints: &'a MultibodyJointSet,
+    velocity_limits: Vec<(RigidBodyHandle, VelocityLimits)>,
 }
 
 #[derive(Deserialize)]
@@ -36,6 +38,7 @@ struct DeserializableWorld {
     colliders: ColliderSet,
     impulse_joints: ImpulseJointSet,
     multibody_joints: MultibodyJointSet,
+    velocity_limits: Vec<(RigidBodyHandle, VelocityLimits)>,
 }
 
 #[wasm_bindgen]
@@ -100,6 +103,13 @@ impl RawSerializationPipeline {
         RawSerializationPipeline
     }
 
+    /// Serializes the complete physics state into a bincode snapshot.
+    ///
+    /// The query pipeline and the CCD solver are not part of the snapshot since they don't hold
+    /// any persistent state: the query pipeline must be updated after deserialization. The velocity
+    /// caps of the rigid-bodies are part of the snapshot.
+    ///
+    /// Returns `undefined` if the serialization failed.
     pub fn serializeAll(
         &self,
         gravity: &RawVector,
@@ -122,11 +132,17 @@ impl RawSerializationPipeline {
             colliders: &colliders.0,
             impulse_joints: &impulse_joints.0,
             multibody_joints: &multibody_joints.0,
+            velocity_limits: bodies.sorted_velocity_limits(),
         };
         let snap = bincode::serialize(&to_serialize).ok()?;
         Some(Uint8Array::from(&snap[..]))
     }
 
+    /// Deserializes a snapshot created with `serializeAll`.
+    ///
+    /// All the rigid-body, collider, and joint handles are preserved by the round-trip.
+    ///
+    /// Returns `undefined` if the data isn't a valid snapshot.
     pub fn deserializeAll(&self, data: Uint8Array) -> Option<RawDeserializedWorld> {
         let data = data.to_vec();
         let d: DeserializableWorld = bincode::deserialize(&data[..]).ok()?;
@@ -136,7 +152,10 @@ impl RawSerializationPipeline {
             islands: Some(RawIslandManager(d.islands)),
             broadPhase: Some(RawBroadPhase(d.broad_phase)),
             narrowPhase: Some(RawNarrowPhase(d.narrow_phase)),
-            bodies: Some(RawRigidBodySet(d.bodies)),
+            bodies: Some(RawRigidBodySet(
+                d.bodies,
+                d.velocity_limits.into_iter().collect(),
+            )),
             colliders: Some(RawColliderSet(d.colliders)),
             impulse_joints: Some(RawImpulseJointSet(d.impulse_joints)),
             multibody_joints: Some(RawMultibodyJointSet(d.multibody_joints)),
@@ -1,6 +1,7 @@
 use rapier::data::Index;
 use rapier::dynamics::{ImpulseJointHandle, MultibodyJointHandle, RigidBodyHandle};
 use rapier::geometry::{Collider, ColliderHandle};
+use std::collections::HashMap;
 use wasm_bindgen::JsValue;
 
 pub type FlatHandle = f64;
@@ -31,6 +32,15 @@ pub fn flat_handle(id: Index) -> FlatHandle {
     FlatHandle::from_bits(i as u64 | ((g as u64) << 32))
 }
 
+/// Builds a map from the `[oldHandle, newHandle]` pairs of a rigid-body handle remap table, as
+/// returned by `RawRigidBodySet::mergeFrom`.
+pub fn body_remap(pairs: &[FlatHandle]) -> HashMap<RigidBodyHandle, RigidBodyHandle> {
+    pairs
+        .chunks_exact(2)
+        .map(|pair| (body_handle(pair[0]), body_handle(pair[1])))
+        .collect()
+}
+
 // pub type FlatHandle = u32;
 //
 // #[inline(always)]
@@ -59,6 +69,11 @@ pub fn flat_handle(id: Index) -> FlatHandle {
 //     i as u32 | ((g as u32) << 16)
 // }
 
+/// Calls `f` with a collider predicate wrapping the given JS function, or with `None` if `filter`
+/// isn't a function.
+///
+/// The JS function is invoked once for every candidate collider of a query, which implies a
+/// WASM/JS boundary crossing per candidate. Prefer filter flags and groups whenever possible.
 #[inline(always)]
 pub fn with_filter<T>(
     filter: &js_sys::Function,
@@ -77,3 +92,19 @@ pub fn with_filter<T>(
         f(None)
     }
 }
+
+/// Throws a JavaScript `Error` reporting that a handle of the given kind doesn't match any object
+/// of its set.
+///
+/// This gives the caller a catchable exception with a meaningful message instead of the opaque
+/// `unreachable` trap of a panic. Because Rust destructors don't run when throwing, the object
+/// set this was called on stays borrowed afterward and every later call on it fails. This is only
+/// a last resort: the TypeScript wrappers check their handle with `contains` before calling into
+/// the raw sets, so that a stale handle never reaches this.
+#[cold]
+pub fn throw_invalid_handle(kind: &str) -> ! {
+    wasm_bindgen::throw_str(&format!(
+        "Invalid {} reference. It may have been removed from the physics World.",
+        kind
+    ))
+}